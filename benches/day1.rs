@@ -0,0 +1,44 @@
+use aoc2023::days::day1::{Decoder, Missing, CHUNK_SIZE, ENGLISH};
+use std::time::{Duration, Instant};
+
+// Throughput of day 1 calibration, in memory and streamed in parallel chunks, over
+// the puzzle input repeated to about 256 MiB. Run with `cargo bench --bench day1`.
+
+const SIZE: usize = 256 << 20;
+
+fn report(name: &str, bytes: usize, f: impl Fn() -> u64) {
+    // best of a few runs, after one to warm up
+    let mut best = Duration::MAX;
+    let mut sum = f();
+    for _ in 0..3 {
+        let start = Instant::now();
+        sum = f();
+        best = best.min(start.elapsed());
+    }
+    let throughput = bytes as f64 / (1 << 20) as f64 / best.as_secs_f64();
+    println!(
+        "{:<24} {:>10.2?} {:>10.1} MiB/s  (sum {})",
+        name, best, throughput, sum
+    );
+}
+
+fn main() {
+    let input = std::fs::read_to_string("./data/1.txt").expect("wrong file path");
+    let line = format!("{}\n", input.trim_end());
+    let document = line.repeat(SIZE / line.len());
+
+    for (part, decoder) in [
+        (1, Decoder::new(&[]).unwrap()),
+        (2, Decoder::new(ENGLISH).unwrap()),
+    ] {
+        report(&format!("part {} in memory", part), document.len(), || {
+            decoder.calibrate(&document, Missing::Error).unwrap().sum
+        });
+        report(&format!("part {} streamed", part), document.len(), || {
+            decoder
+                .calibrate_reader(document.as_bytes(), Missing::Error, CHUNK_SIZE)
+                .unwrap()
+                .sum
+        });
+    }
+}
@@ -0,0 +1,80 @@
+/* Advent of Code 2023 solvers. Generated by cbindgen from src/ffi.rs; do not edit. */
+
+#ifndef AOC2023_H
+#define AOC2023_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Outcome of `aoc_solve`.
+ */
+typedef enum AocStatus {
+  /**
+   * The answer was written to the output buffer.
+   */
+  AOC_STATUS_OK = 0,
+  /**
+   * The day or part does not exist.
+   */
+  AOC_STATUS_NO_SUCH_PART = 1,
+  /**
+   * The input is not valid UTF-8.
+   */
+  AOC_STATUS_INVALID_UTF8 = 2,
+  /**
+   * The output buffer (or the input with a non-zero length) is NULL.
+   */
+  AOC_STATUS_NULL_ARGUMENT = 3,
+  /**
+   * The answer did not fit; `len` holds the length it needs, without the NUL.
+   */
+  AOC_STATUS_BUFFER_TOO_SMALL = 4,
+  /**
+   * The solver panicked, usually on malformed input.
+   */
+  AOC_STATUS_PANICKED = 5,
+  /**
+   * An accumulation overflowed (only detected with the `checked` feature).
+   */
+  AOC_STATUS_OVERFLOW = 6,
+} AocStatus;
+
+/**
+ * Caller-owned output buffer. On return `data` holds a NUL-terminated answer, or
+ * an error message truncated to fit, and `len` its length without the NUL.
+ */
+typedef struct AocBuffer {
+  char *data;
+  size_t capacity;
+  size_t len;
+} AocBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Solves part `part` of day `day` for the `len` bytes of puzzle text at `input_ptr`
+ * and writes the answer as text to `out_buf`.
+ *
+ * # Safety
+ *
+ * `input_ptr` must point to `len` readable bytes (it may be NULL when `len` is 0),
+ * `out_buf` must point to a valid `AocBuffer`, and its `data` must point to
+ * `capacity` writable bytes.
+ */
+enum AocStatus aoc_solve(uint32_t day,
+                         uint32_t part,
+                         const uint8_t *input_ptr,
+                         size_t len,
+                         struct AocBuffer *out_buf);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC2023_H */
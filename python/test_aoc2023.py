@@ -0,0 +1,59 @@
+"""Tests for the Python bindings.
+
+Build the extension and run from the repository root:
+
+    maturin develop --release && python -m unittest python/test_aoc2023.py
+"""
+
+import unittest
+from pathlib import Path
+
+import aoc2023
+
+DATA = Path(__file__).resolve().parent.parent / "data"
+
+
+def read(name):
+    return (DATA / name).read_text()
+
+
+class SolveTest(unittest.TestCase):
+    def test_examples(self):
+        self.assertEqual(aoc2023.solve(1, 1, read("1t1.txt")), 142)
+        self.assertEqual(aoc2023.solve(5, 2, read("5t.txt")), 46)
+
+    def test_big_answer(self):
+        answer = aoc2023.solve(8, 2, read("8.txt"))
+        self.assertIsInstance(answer, int)
+        self.assertEqual(answer, 14631604759649)
+
+    def test_errors(self):
+        with self.assertRaises(ValueError):
+            aoc2023.solve(25, 2, "")
+        with self.assertRaises(RuntimeError):
+            aoc2023.solve(1, 1, "no digits here")
+
+
+class HelperTest(unittest.TestCase):
+    def test_arrangements(self):
+        self.assertEqual(aoc2023.arrangements("???.###", [1, 1, 3]), 1)
+        self.assertEqual(aoc2023.arrangements("?###????????", [3, 2, 1]), 10)
+        with self.assertRaises(ValueError):
+            aoc2023.arrangements("???", [])
+
+    def test_almanac(self):
+        almanac = aoc2023.Almanac(read("5t.txt"))
+        self.assertEqual([almanac.map(s) for s in (79, 14, 55, 13)], [82, 43, 86, 35])
+        self.assertEqual(almanac.map_ranges([(82, 1)]), [(46, 1)])
+        lowest = min(start for start, _ in almanac.map_ranges([(79, 14), (55, 13)]))
+        self.assertEqual(lowest, 46)
+        self.assertEqual(
+            almanac.seeds_below(47),
+            [(0, 22), (26, 18), (50, 4), (70, 1), (82, 1), (99, 1)],
+        )
+        with self.assertRaises(ValueError):
+            aoc2023.Almanac("seed-to-soil map:\n50 98\n")
+
+
+if __name__ == "__main__":
+    unittest.main()
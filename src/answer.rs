@@ -0,0 +1,209 @@
+use num::bigint::ParseBigIntError;
+use num::BigInt;
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// A puzzle answer. Every day returns one of these so that answers of different
+/// widths (or ones that are not numbers at all) can be handled uniformly.
+///
+/// Answers compare by value: `Answer::Unsigned(5) == Answer::Signed(5)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "Repr", try_from = "Repr")]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Big(BigInt),
+    Text(String),
+}
+
+/// Serialized form of an `Answer`; big integers are written as strings so that
+/// consumers without arbitrary-precision numbers can still read them.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+enum Repr {
+    Signed(i128),
+    Unsigned(u128),
+    Big(String),
+    Text(String),
+}
+
+impl From<Answer> for Repr {
+    fn from(answer: Answer) -> Self {
+        match answer {
+            Answer::Signed(n) => Repr::Signed(n),
+            Answer::Unsigned(n) => Repr::Unsigned(n),
+            Answer::Big(n) => Repr::Big(n.to_string()),
+            Answer::Text(s) => Repr::Text(s),
+        }
+    }
+}
+
+impl TryFrom<Repr> for Answer {
+    type Error = ParseBigIntError;
+
+    fn try_from(repr: Repr) -> Result<Self, Self::Error> {
+        Ok(match repr {
+            Repr::Signed(n) => Answer::Signed(n),
+            Repr::Unsigned(n) => Answer::Unsigned(n),
+            Repr::Big(n) => Answer::Big(n.parse()?),
+            Repr::Text(s) => Answer::Text(s),
+        })
+    }
+}
+
+impl Answer {
+    fn to_bigint(&self) -> Option<BigInt> {
+        match self {
+            Answer::Signed(n) => Some(BigInt::from(*n)),
+            Answer::Unsigned(n) => Some(BigInt::from(*n)),
+            Answer::Big(n) => Some(n.clone()),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => n.fmt(f),
+            Answer::Unsigned(n) => n.fmt(f),
+            Answer::Big(n) => n.fmt(f),
+            Answer::Text(s) => f.pad(s),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Text(_), _) | (_, Answer::Text(_)) => self.to_string() == other.to_string(),
+            _ => self.to_bigint() == other.to_bigint(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+/// Parses a recorded answer, picking the narrowest numeric variant that fits.
+/// Anything that is not an integer becomes `Answer::Text`.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(if let Ok(n) = s.parse::<u128>() {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = s.parse::<i128>() {
+            Answer::Signed(n)
+        } else if let Ok(n) = s.parse::<BigInt>() {
+            Answer::Big(n)
+        } else {
+            Answer::Text(s.to_string())
+        })
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident, $wide:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as $wide)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    let other: Answer = (*other).into();
+                    *self == other
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Signed, i128, i8, i16, i32, i64, i128, isize);
+impl_from_int!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        Answer::Big(n)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        let other: Answer = other.parse().unwrap();
+        *self == other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-5i32).to_string(), "-5");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("LRL").to_string(), "LRL");
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Answer::from(5u32), Answer::from(5i64));
+        assert_eq!(Answer::Big(BigInt::from(7)), Answer::from(7usize));
+        assert_ne!(Answer::from(-1i32), Answer::from(u128::MAX));
+        assert_ne!(Answer::from("5"), Answer::from("five"));
+        assert_eq!(Answer::from(5u8), 5);
+        assert_eq!(Answer::from(233283622908263i64), "233283622908263");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("42".parse(), Ok(Answer::Unsigned(42)));
+        assert!(matches!("-42".parse(), Ok(Answer::Signed(-42))));
+        assert!(matches!(
+            "1000000000000000000000000000000000000000".parse(),
+            Ok(Answer::Big(_))
+        ));
+        assert!(matches!("abc".parse(), Ok(Answer::Text(_))));
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let big: BigInt = "-1000000000000000000000000000000000000000".parse().unwrap();
+        for answer in [
+            Answer::Signed(-3),
+            Answer::Unsigned(u128::MAX),
+            Answer::Big(big),
+            Answer::Text("hello".to_string()),
+        ] {
+            let json = serde_json::to_string(&answer).unwrap();
+            let back: Answer = serde_json::from_str(&json).unwrap();
+            assert_eq!(answer, back);
+            assert_eq!(answer.to_string(), back.to_string());
+        }
+        assert_eq!(
+            serde_json::to_string(&Answer::Signed(-3)).unwrap(),
+            r#"{"type":"signed","value":-3}"#
+        );
+    }
+}
@@ -0,0 +1,375 @@
+use aoc2023::cache::{self, Cache};
+use aoc2023::cancel::{self, Cancelled, Token};
+use aoc2023::checked::Overflow;
+use aoc2023::days::{self, DAYS};
+use aoc2023::explain;
+use aoc2023::lint::lint;
+#[cfg(feature = "memory")]
+use aoc2023::memory::Counting;
+use aoc2023::memory::{self, Usage};
+use aoc2023::repl::{Outcome, Repl};
+use aoc2023::utils;
+use clap::{ArgAction, Args, Parser, Subcommand};
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use std::fs::File;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::prelude::*;
+
+// counting slows every allocation down, so plain runs keep the system allocator
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOC: Counting = Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 runner")]
+struct Cli {
+    /// Log solver progress to stderr; repeat for more detail
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Also write logs as JSON lines to this file
+    #[arg(long, global = true)]
+    log_json: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or more days and print the answers
+    Run(RunArgs),
+    /// Manage the cache of answers kept by `run`
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Check an input against the structural assumptions a day's solver makes
+    Lint { day: u32, file: PathBuf },
+    /// Explore a day's parsed model interactively; `help` lists the commands
+    Repl {
+        day: u32,
+        /// Read the input from this file instead of ./data/<day>.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Days to run; all days when omitted
+    days: Vec<u32>,
+    /// Only run this part
+    #[arg(long)]
+    part: Option<u32>,
+    /// Read the input from this file instead of ./data/<day>.txt
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Print the intermediate results each answer was derived from
+    #[arg(long)]
+    explain: bool,
+    /// Give up on a part after this many seconds
+    #[arg(long)]
+    timeout: Option<u64>,
+    /// Solve every part even if its answer is cached
+    #[arg(long)]
+    no_cache: bool,
+    /// Also report each part's peak heap use and allocation count; needs the runner
+    /// built with the `memory` feature
+    #[arg(long)]
+    memory: bool,
+}
+
+#[derive(Subcommand)]
+enum CacheAction {
+    /// Remove every cached answer
+    Clear,
+}
+
+/// Sets up logging: warnings only by default, `-v` for solve spans and timings,
+/// `-vv` for per-phase events and `-vvv` for everything. `RUST_LOG` overrides the
+/// level when set.
+fn init_logging(verbose: u8, log_json: Option<&Path>) -> std::io::Result<()> {
+    let level = match verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    let filter = || {
+        EnvFilter::builder()
+            .with_default_directive(level.into())
+            .from_env_lossy()
+    };
+
+    let stderr = tracing_subscriber::fmt::layer()
+        .with_writer(std::io::stderr)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_filter(filter());
+    let json = match log_json {
+        Some(path) => Some(
+            tracing_subscriber::fmt::layer()
+                .json()
+                .with_writer(Mutex::new(File::create(path)?))
+                .with_span_events(FmtSpan::CLOSE)
+                .with_filter(filter()),
+        ),
+        None => None,
+    };
+    tracing_subscriber::registry()
+        .with(stderr)
+        .with(json)
+        .init();
+    Ok(())
+}
+
+/// Memory columns of a result row, empty unless `--memory` was given.
+fn memory_columns(memory: bool, usage: Option<Usage>) -> String {
+    match (memory, usage) {
+        (false, _) => String::new(),
+        (true, None) => format!("  {:>10}  {:>10}", "-", "-"),
+        (true, Some(usage)) => format!(
+            "  {:>10}  {:>10}",
+            memory::format_bytes(usage.peak),
+            usage.allocations
+        ),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let RunArgs {
+        days,
+        part,
+        input,
+        explain,
+        timeout,
+        no_cache,
+        memory,
+    } = args;
+    if memory && !cfg!(feature = "memory") {
+        eprintln!("--memory needs the runner built with `--features memory`");
+        return ExitCode::FAILURE;
+    }
+    let timeout = timeout.map(Duration::from_secs);
+    let cache = (!no_cache).then(|| Cache::new(cache::DEFAULT_DIR));
+    let days = if days.is_empty() {
+        DAYS.collect()
+    } else {
+        days
+    };
+    if let Some(day) = days.iter().find(|d| !DAYS.contains(d)) {
+        eprintln!("no such day: {}", day);
+        return ExitCode::FAILURE;
+    }
+
+    // overflows and timeouts are reported in the table, so keep the default hook from
+    // printing them
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !info.payload().is::<Overflow>() && !info.payload().is::<Cancelled>() {
+            default_hook(info);
+        }
+    }));
+
+    let mut failed = false;
+    let memory_header = if memory {
+        format!("  {:>10}  {:>10}", "Peak", "Allocs")
+    } else {
+        String::new()
+    };
+    println!(
+        "{:>3}  {:>4}  {:>20}  {:>10}{}",
+        "Day", "Part", "Answer", "Time", memory_header
+    );
+    for day in days {
+        let path = input.clone().unwrap_or_else(|| days::input_path(day));
+        let text = utils::read_input(&path).expect("wrong file path");
+
+        for p in 1..=days::parts(day) {
+            if part.is_some_and(|part| part != p) {
+                continue;
+            }
+            let start = Instant::now();
+            // explanations need the solver to actually run
+            let cached = cache
+                .as_ref()
+                .filter(|_| !explain)
+                .and_then(|cache| cache.get(day, p, &text));
+            if let Some(answer) = cached {
+                let elapsed = start.elapsed();
+                println!(
+                    "{:>3}  {:>4}  {:>20}  {:>10.2?}{}  (cached)",
+                    day,
+                    p,
+                    answer,
+                    elapsed,
+                    memory_columns(memory, None)
+                );
+                continue;
+            }
+            let token = timeout.map_or_else(Token::new, Token::with_timeout);
+            let (result, usage) = memory::measure(|| {
+                panic::catch_unwind(AssertUnwindSafe(|| {
+                    cancel::with_token(token, || {
+                        if explain {
+                            explain::collect(|| days::solve(day, p, &text))
+                        } else {
+                            (days::solve(day, p, &text), Vec::new())
+                        }
+                    })
+                }))
+            });
+            let elapsed = start.elapsed();
+            let usage = memory_columns(memory, Some(usage));
+            match result {
+                Ok((answer, records)) => {
+                    let answer = answer.unwrap();
+                    println!(
+                        "{:>3}  {:>4}  {:>20}  {:>10.2?}{}",
+                        day, p, answer, elapsed, usage
+                    );
+                    if let Some(cache) = &cache {
+                        if let Err(err) = cache.put(day, p, &text, &answer) {
+                            tracing::warn!(%err, "cannot cache answer");
+                        }
+                    }
+                    for record in records {
+                        println!("           {}", record);
+                    }
+                }
+                Err(payload) => {
+                    failed = true;
+                    let status = if let Some(overflow) = payload.downcast_ref::<Overflow>() {
+                        format!("overflow: {}", overflow.expr)
+                    } else if payload.is::<Cancelled>() {
+                        "timed out".to_string()
+                    } else {
+                        "panicked".to_string()
+                    };
+                    println!(
+                        "{:>3}  {:>4}  {:>20}  {:>10.2?}{}",
+                        day, p, status, elapsed, usage
+                    );
+                }
+            }
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Where `aoc repl` keeps the lines entered across sessions.
+const HISTORY: &str = "target/aoc-repl-history";
+
+fn repl(day: u32, input: Option<PathBuf>) -> ExitCode {
+    if !DAYS.contains(&day) {
+        eprintln!("no such day: {}", day);
+        return ExitCode::FAILURE;
+    }
+    let path = input.unwrap_or_else(|| days::input_path(day));
+    let text = match utils::read_input(&path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("cannot read {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut repl = match Repl::new(day, text.to_string()) {
+        Ok(repl) => repl,
+        Err(err) => {
+            eprintln!("cannot parse {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut editor = match DefaultEditor::new() {
+        Ok(editor) => editor,
+        Err(err) => {
+            eprintln!("cannot start the line editor: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let _ = editor.load_history(HISTORY);
+
+    let prompt = format!("day{}> ", day);
+    loop {
+        let line = match editor.readline(&prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                break;
+            }
+        };
+        if !line.trim().is_empty() {
+            let _ = editor.add_history_entry(line.as_str());
+        }
+        // a command failing on bad arguments is reported as an error line instead, so
+        // keep the hook quiet while it runs
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let result = repl.eval(&line);
+        panic::set_hook(hook);
+        match result {
+            Ok(Outcome::Print(out)) if out.is_empty() => {}
+            Ok(Outcome::Print(out)) => println!("{}", out),
+            Ok(Outcome::Quit) => break,
+            Err(err) => println!("error: {}", err),
+        }
+    }
+
+    if let Err(err) = editor.save_history(HISTORY) {
+        tracing::warn!(%err, "cannot save repl history");
+    }
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Err(err) = init_logging(cli.verbose, cli.log_json.as_deref()) {
+        eprintln!("cannot open log file: {}", err);
+        return ExitCode::FAILURE;
+    }
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Cache {
+            action: CacheAction::Clear,
+        } => match Cache::new(cache::DEFAULT_DIR).clear() {
+            Ok(removed) => {
+                println!("removed {} cached answers", removed);
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("cannot clear the cache: {}", err);
+                ExitCode::FAILURE
+            }
+        },
+        Command::Lint { day, file } => {
+            let input = utils::read_input(&file).expect("wrong file path");
+            match lint(day, &input) {
+                Some(report) => {
+                    print!("{}", report);
+                    if report.is_clean() {
+                        ExitCode::SUCCESS
+                    } else {
+                        ExitCode::FAILURE
+                    }
+                }
+                None => {
+                    println!("Day {}: no structural checks", day);
+                    ExitCode::SUCCESS
+                }
+            }
+        }
+        Command::Repl { day, input } => repl(day, input),
+    }
+}
@@ -0,0 +1,41 @@
+use aoc2023::server::{serve, Config};
+use clap::Parser;
+use std::process::ExitCode;
+use std::time::Duration;
+use tiny_http::Server;
+
+#[derive(Parser)]
+#[command(name = "server", about = "Advent of Code 2023 solvers over HTTP")]
+struct Cli {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8023")]
+    addr: String,
+    /// Seconds a solve may take before the request fails
+    #[arg(long, default_value_t = 30)]
+    timeout: u64,
+    /// Largest accepted puzzle input in bytes
+    #[arg(long, default_value_t = 1 << 20)]
+    max_body: usize,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .init();
+
+    let server = match Server::http(&cli.addr) {
+        Ok(server) => server,
+        Err(err) => {
+            eprintln!("cannot listen on {}: {}", cli.addr, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let config = Config {
+        timeout: Duration::from_secs(cli.timeout),
+        max_body: cli.max_body,
+    };
+    tracing::info!(addr = cli.addr, "listening");
+    serve(&server, &config);
+    ExitCode::SUCCESS
+}
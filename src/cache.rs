@@ -0,0 +1,87 @@
+use crate::answer::Answer;
+use crate::days;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+// On-disk cache of answers for the runner, one JSON file per entry. An entry is
+// keyed by the day, the part, a hash of the input and the solver version, so edited
+// inputs and changed solvers miss instead of returning stale answers.
+
+/// Where the runner keeps its cache; `cargo clean` clears it as well.
+pub const DEFAULT_DIR: &str = "target/aoc-cache";
+
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    fn path(&self, day: u32, part: u32, input: &str) -> PathBuf {
+        let hash: String = Sha256::digest(input.as_bytes())
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        let version = format!("{}.{}", env!("CARGO_PKG_VERSION"), days::version(day));
+        self.dir
+            .join(format!("{}-{}-{}-{}.json", day, part, version, hash))
+    }
+
+    /// The cached answer, if there is a readable one.
+    pub fn get(&self, day: u32, part: u32, input: &str) -> Option<Answer> {
+        let json = fs::read_to_string(self.path(day, part, input)).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    pub fn put(&self, day: u32, part: u32, input: &str, answer: &Answer) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let json = serde_json::to_string(answer).map_err(io::Error::other)?;
+        fs::write(self.path(day, part, input), json)
+    }
+
+    /// Removes every entry, returning how many there were.
+    pub fn clear(&self) -> io::Result<usize> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(err),
+        };
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-test-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        assert_eq!(cache.clear().unwrap(), 0);
+
+        assert_eq!(cache.get(1, 1, "1abc2"), None);
+        cache.put(1, 1, "1abc2", &Answer::from(12)).unwrap();
+        cache.put(9, 1, "1abc2", &Answer::from(-3)).unwrap();
+        assert_eq!(cache.get(1, 1, "1abc2"), Some(Answer::from(12)));
+        assert_eq!(cache.get(9, 1, "1abc2"), Some(Answer::from(-3)));
+        assert_eq!(cache.get(1, 2, "1abc2"), None);
+        assert_eq!(cache.get(1, 1, "1abc3"), None);
+
+        assert_eq!(cache.clear().unwrap(), 2);
+        assert_eq!(cache.get(1, 1, "1abc2"), None);
+        fs::remove_dir(dir).unwrap();
+    }
+}
@@ -0,0 +1,114 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// Cooperative cancellation for solvers that can loop for a very long time (or
+// forever) on bad inputs. A caller runs the solver under `with_token`, and the
+// solver calls `check` in its long-running loops; once the token is cancelled or
+// past its deadline, `check` unwinds with a `Cancelled` payload, the same way
+// `checked` reports overflows. Outside `with_token`, `check` does nothing.
+
+/// Panic payload raised by `check` when the current token is cancelled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+/// A cancellation flag shared between a solver and whoever may want it stopped.
+#[derive(Debug, Clone, Default)]
+pub struct Token {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl Token {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A token that cancels itself once `timeout` has passed.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Token {
+            cancelled: Arc::default(),
+            deadline: Some(Instant::now() + timeout),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` as the current thread's token.
+pub fn with_token<T>(token: Token, f: impl FnOnce() -> T) -> T {
+    // puts back the previous token, also when `f` unwinds
+    struct Restore(Option<Token>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT.with(|current| current.replace(Some(token))));
+    f()
+}
+
+/// Unwinds with `Cancelled` if the current thread's token has been cancelled.
+#[inline]
+pub fn check() {
+    let cancelled =
+        CURRENT.with(|current| current.borrow().as_ref().is_some_and(Token::is_cancelled));
+    if cancelled {
+        std::panic::panic_any(Cancelled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    #[test]
+    fn test_check() {
+        check();
+
+        let token = Token::new();
+        with_token(token.clone(), check);
+        token.cancel();
+        let err = panic::catch_unwind(|| with_token(token, check)).unwrap_err();
+        assert!(err.is::<Cancelled>());
+
+        // the cancelled token is gone once `with_token` returns
+        check();
+    }
+
+    #[test]
+    fn test_timeout() {
+        let token = Token::with_timeout(Duration::from_millis(10));
+        assert!(!token.is_cancelled());
+        let err = panic::catch_unwind(|| {
+            with_token(token, || loop {
+                check();
+            })
+        })
+        .unwrap_err();
+        assert!(err.is::<Cancelled>());
+    }
+}
@@ -0,0 +1,96 @@
+use num::traits::{CheckedAdd, CheckedMul, One};
+use std::fmt;
+
+// Arithmetic helpers for accumulations that could outgrow their integer type on
+// bigger inputs. With the `checked` feature every operation is checked and an
+// overflow panics with an `Overflow` payload naming the day and expression; without
+// it they compile down to the plain operators.
+
+/// Panic payload raised when a checked operation overflows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub day: u32,
+    pub expr: &'static str,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "day {} overflowed in `{}`", self.day, self.expr)
+    }
+}
+
+#[cfg(feature = "checked")]
+fn overflow(day: u32, expr: &'static str) -> ! {
+    std::panic::panic_any(Overflow { day, expr })
+}
+
+#[inline]
+pub fn add<T: CheckedAdd>(day: u32, expr: &'static str, a: T, b: T) -> T {
+    #[cfg(feature = "checked")]
+    return a.checked_add(&b).unwrap_or_else(|| overflow(day, expr));
+    #[cfg(not(feature = "checked"))]
+    {
+        let _ = (day, expr);
+        a + b
+    }
+}
+
+#[inline]
+pub fn mul<T: CheckedMul>(day: u32, expr: &'static str, a: T, b: T) -> T {
+    #[cfg(feature = "checked")]
+    return a.checked_mul(&b).unwrap_or_else(|| overflow(day, expr));
+    #[cfg(not(feature = "checked"))]
+    {
+        let _ = (day, expr);
+        a * b
+    }
+}
+
+#[inline]
+pub fn pow<T: CheckedMul + One + Clone>(day: u32, expr: &'static str, base: T, exp: usize) -> T {
+    #[cfg(feature = "checked")]
+    return num::traits::checked_pow(base, exp).unwrap_or_else(|| overflow(day, expr));
+    #[cfg(not(feature = "checked"))]
+    {
+        let _ = (day, expr);
+        num::traits::pow(base, exp)
+    }
+}
+
+/// Sums an iterator with `add`.
+pub fn sum<T: CheckedAdd + Default>(
+    day: u32,
+    expr: &'static str,
+    iter: impl IntoIterator<Item = T>,
+) -> T {
+    iter.into_iter()
+        .fold(T::default(), |acc, x| add(day, expr, acc, x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_range() {
+        assert_eq!(add(0, "a + b", 2u8, 3), 5);
+        assert_eq!(mul(0, "a * b", 16u8, 15), 240);
+        assert_eq!(pow(0, "a ^ b", 2u8, 7), 128);
+        assert_eq!(sum(0, "sum", [1u64, 2, 3]), 6);
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    fn test_overflow_payload() {
+        let err = std::panic::catch_unwind(|| mul(8, "a * b", 16u8, 16)).unwrap_err();
+        let overflow = err.downcast_ref::<Overflow>().unwrap();
+        assert_eq!(
+            overflow,
+            &Overflow {
+                day: 8,
+                expr: "a * b"
+            }
+        );
+        assert_eq!(overflow.to_string(), "day 8 overflowed in `a * b`");
+    }
+}
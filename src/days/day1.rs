@@ -0,0 +1,404 @@
+use crate::answer::Answer;
+use crate::checked;
+use crate::explain::{self, Record};
+use crate::utils;
+use aho_corasick::AhoCorasick;
+use rayon::prelude::*;
+use std::cmp::Reverse;
+use std::fmt;
+use std::io::{self, Read};
+
+pub fn solution1(input: &str) -> Answer {
+    solve(&Decoder::new(&[]).unwrap(), input)
+}
+
+/// Spelled-out digits of the puzzle, `one` to `nine`.
+pub const ENGLISH: &[(&str, u32)] = &[
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// Chunk size for `Decoder::calibrate_reader` that keeps every thread busy for a
+/// while per chunk without holding much of the input.
+pub const CHUNK_SIZE: usize = 1 << 20;
+
+/// What to do with a line that has no digit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Missing {
+    /// Fail, listing every such line.
+    #[default]
+    Error,
+    /// Leave the line out of the calibration.
+    Skip,
+    /// Count the line with a calibration value of 0.
+    Zero,
+}
+
+/// Outcome of calibrating a document.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Calibration {
+    /// Wide enough for documents far larger than memory, which `calibrate_reader`
+    /// streams.
+    pub sum: u64,
+    /// Lines that contributed a value, including zeroed ones.
+    pub counted: usize,
+    /// 1-based numbers of the lines without a digit.
+    pub missing: Vec<usize>,
+}
+
+/// Error for `Missing::Error`: the 1-based numbers of the lines without a digit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoDigits {
+    pub lines: Vec<usize>,
+}
+
+impl fmt::Display for NoDigits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let lines: Vec<String> = self.lines.iter().map(|n| n.to_string()).collect();
+        let plural = if self.lines.len() == 1 { "" } else { "s" };
+        write!(f, "no digits on line{} {}", plural, lines.join(", "))
+    }
+}
+
+impl std::error::Error for NoDigits {}
+
+/// Finds the first and last digit of calibration lines, where a digit is an ASCII
+/// digit or any token of a configurable dictionary (several languages, `zero`,
+/// custom words). All tokens go into one Aho–Corasick automaton, so a line is
+/// scanned once, and overlapping tokens such as `eightwo` are all found.
+///
+/// Other Unicode numerics (`٣`, `½`, `Ⅻ`) are ordinary characters: they are not
+/// always a single decimal digit, so rather than guess, add the ones an input uses
+/// to the dictionary.
+#[derive(Debug)]
+pub struct Decoder {
+    automaton: AhoCorasick,
+    digits: Vec<u32>,
+}
+
+impl Decoder {
+    /// A decoder for ASCII digits and the `(token, digit)` pairs of `words`. Fails on
+    /// an empty token or one that does not map to a digit 0-9.
+    pub fn new<'a>(words: impl IntoIterator<Item = &'a (&'a str, u32)>) -> Result<Self, String> {
+        const ASCII: [(&str, u32); 10] = [
+            ("0", 0),
+            ("1", 1),
+            ("2", 2),
+            ("3", 3),
+            ("4", 4),
+            ("5", 5),
+            ("6", 6),
+            ("7", 7),
+            ("8", 8),
+            ("9", 9),
+        ];
+        let (tokens, digits): (Vec<&str>, Vec<u32>) = ASCII.iter().chain(words).copied().unzip();
+        if tokens.iter().any(|token| token.is_empty()) {
+            return Err("empty token".to_string());
+        }
+        if let Some((token, digit)) = tokens.iter().zip(&digits).find(|(_, &d)| d > 9) {
+            return Err(format!(
+                "token `{}` maps to {}, not a digit 0-9",
+                token, digit
+            ));
+        }
+        let automaton = AhoCorasick::new(&tokens).map_err(|err| err.to_string())?;
+        Ok(Decoder { automaton, digits })
+    }
+
+    /// The first and last digit of a line, by where their tokens start. When tokens
+    /// start at the same place, the longest one counts.
+    pub fn first_last(&self, line: &str) -> Option<(u32, u32)> {
+        let mut first: Option<((usize, Reverse<usize>), u32)> = None;
+        let mut last: Option<((usize, usize), u32)> = None;
+        for m in self.automaton.find_overlapping_iter(line) {
+            let digit = self.digits[m.pattern().as_usize()];
+            let key = (m.start(), Reverse(m.len()));
+            if first.map_or(true, |(first, _)| key < first) {
+                first = Some((key, digit));
+            }
+            let key = (m.start(), m.len());
+            if last.map_or(true, |(last, _)| key > last) {
+                last = Some((key, digit));
+            }
+        }
+        Some((first?.1, last?.1))
+    }
+
+    /// Sums the calibration values, the first and last digit of each line, handling
+    /// lines without a digit as `missing` says.
+    pub fn calibrate(&self, input: &str, missing: Missing) -> Result<Calibration, NoDigits> {
+        let (calibration, last_line) = self.calibrate_lines(input, 0, missing);
+        finish(calibration, last_line, missing)
+    }
+
+    /// Like `calibrate`, but streams `reader` in line-aligned chunks of about
+    /// `chunk_size` bytes and decodes one chunk per thread at a time with rayon, so
+    /// memory stays at a few chunks however large the input is. Lines without digits
+    /// fail with `InvalidData` wrapping `NoDigits`. Explanations are not recorded.
+    pub fn calibrate_reader(
+        &self,
+        reader: impl Read,
+        missing: Missing,
+        chunk_size: usize,
+    ) -> io::Result<Calibration> {
+        let mut chunks = utils::Chunks::new(reader, chunk_size);
+        let batch = rayon::current_num_threads();
+        let mut total = Calibration::default();
+        let mut last_line = 0;
+        let mut lines = 0;
+        loop {
+            let texts = chunks
+                .by_ref()
+                .take(batch)
+                .collect::<io::Result<Vec<_>>>()?;
+            if texts.is_empty() {
+                break;
+            }
+            // every chunk but the last ends with a newline, so counting them numbers
+            // the lines
+            let starts: Vec<usize> = texts
+                .iter()
+                .map(|text| {
+                    let start = lines;
+                    lines += text.bytes().filter(|&b| b == b'\n').count();
+                    start
+                })
+                .collect();
+            let parts: Vec<_> = texts
+                .par_iter()
+                .zip(starts)
+                .map(|(text, start)| self.calibrate_lines(text, start, missing))
+                .collect();
+            for (part, part_last_line) in parts {
+                total.sum = checked::add(1, "calibration sum", total.sum, part.sum);
+                total.counted += part.counted;
+                total.missing.extend(part.missing);
+                last_line = last_line.max(part_last_line);
+            }
+        }
+        finish(total, last_line, missing)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Calibrates the lines of `text`, numbering them from `first_line + 1`. Also
+    /// returns the number of the last line that is not blank, or 0 if all are.
+    fn calibrate_lines(
+        &self,
+        text: &str,
+        first_line: usize,
+        missing: Missing,
+    ) -> (Calibration, usize) {
+        let mut calibration = Calibration::default();
+        let mut last_line = 0;
+        for (n, line) in text.lines().enumerate() {
+            let n = first_line + n;
+            if !line.trim().is_empty() {
+                last_line = n + 1;
+            }
+            let (first, last) = match self.first_last(line) {
+                Some(digits) => digits,
+                None => {
+                    tracing::debug!(line = n + 1, "no digits");
+                    calibration.missing.push(n + 1);
+                    match missing {
+                        Missing::Error | Missing::Skip => continue,
+                        Missing::Zero => (0, 0),
+                    }
+                }
+            };
+            tracing::trace!(line, first, last, "digits");
+            explain::record(|| Record::Digits {
+                line: n + 1,
+                first,
+                last,
+            });
+            let value = (first * 10 + last) as u64;
+            calibration.sum = checked::add(1, "calibration sum", calibration.sum, value);
+            calibration.counted += 1;
+        }
+        (calibration, last_line)
+    }
+}
+
+/// Applies the `missing` policy once every line is decoded. Blank lines after
+/// `last_line` are not part of the document (see `utils::normalize`).
+fn finish(
+    mut calibration: Calibration,
+    last_line: usize,
+    missing: Missing,
+) -> Result<Calibration, NoDigits> {
+    while calibration.missing.last().is_some_and(|&n| n > last_line) {
+        calibration.missing.pop();
+        if missing == Missing::Zero {
+            calibration.counted -= 1;
+        }
+    }
+    match missing {
+        Missing::Error if !calibration.missing.is_empty() => Err(NoDigits {
+            lines: calibration.missing,
+        }),
+        _ => Ok(calibration),
+    }
+}
+
+fn solve(decoder: &Decoder, input: &str) -> Answer {
+    match decoder.calibrate(input, Missing::Error) {
+        Ok(calibration) => calibration.sum.into(),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+pub fn solution2(input: &str) -> Answer {
+    solve(&Decoder::new(ENGLISH).unwrap(), input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+
+    #[test]
+    fn test_solution1() {
+        let input = std::fs::read_to_string("./data/1t1.txt").expect("wrong file path");
+        assert_eq!(solution1(&input), 142);
+    }
+
+    #[test]
+    fn test_solution2() {
+        let input = std::fs::read_to_string("./data/1t2.txt").expect("wrong file path");
+        assert_eq!(solution2(&input), 281);
+    }
+
+    #[test]
+    fn test_decoder() {
+        let english = Decoder::new(ENGLISH).unwrap();
+        assert_eq!(english.first_last("eightwo"), Some((8, 2)));
+        assert_eq!(english.first_last("xtwone3four"), Some((2, 4)));
+        assert_eq!(english.first_last("oneight"), Some((1, 8)));
+        assert_eq!(english.first_last("7"), Some((7, 7)));
+        assert_eq!(english.first_last("zero"), None);
+
+        const GERMAN: &[(&str, u32)] = &[("null", 0), ("eins", 1), ("zwei", 2), ("drei", 3)];
+        let both = Decoder::new(ENGLISH.iter().chain(GERMAN).chain(&[("zero", 0)])).unwrap();
+        assert_eq!(both.first_last("zweione"), Some((2, 1)));
+        assert_eq!(both.first_last("xzerodreinull"), Some((0, 0)));
+        let calibration = both.calibrate("eins5\nzero", Missing::Error).unwrap();
+        assert_eq!(calibration.sum, 15);
+
+        // tokens starting at the same place: the longest counts
+        let custom = Decoder::new(&[("sev", 3), ("seven", 7)]).unwrap();
+        assert_eq!(custom.first_last("seven"), Some((7, 7)));
+    }
+
+    #[test]
+    fn test_missing_digits() {
+        let input = "a1b\nnothing\n2c3\n\u{663}\u{bd}";
+        let digits = Decoder::new(&[]).unwrap();
+        assert_eq!(
+            digits.calibrate(input, Missing::Error),
+            Err(NoDigits { lines: vec![2, 4] })
+        );
+        let skipped = digits.calibrate(input, Missing::Skip).unwrap();
+        assert_eq!((skipped.sum, skipped.counted), (34, 2));
+        assert_eq!(skipped.missing, vec![2, 4]);
+        let zeroed = digits.calibrate(input, Missing::Zero).unwrap();
+        assert_eq!((zeroed.sum, zeroed.counted), (34, 4));
+        assert_eq!(
+            NoDigits { lines: vec![2, 4] }.to_string(),
+            "no digits on lines 2, 4"
+        );
+    }
+
+    #[test]
+    fn test_calibrate_reader() {
+        let input = std::fs::read_to_string("./data/1.txt").unwrap();
+        let crlf = input.replace('\n', "\r\n") + "\r\n\r\n";
+        for (decoder, answer) in [
+            (Decoder::new(&[]).unwrap(), solution1(&input)),
+            (Decoder::new(ENGLISH).unwrap(), solution2(&input)),
+        ] {
+            for chunk_size in [1, 100, 4096, 1 << 20] {
+                let calibration = decoder
+                    .calibrate_reader(crlf.as_bytes(), Missing::Error, chunk_size)
+                    .unwrap();
+                assert_eq!(Answer::from(calibration.sum), answer);
+                assert_eq!(calibration.counted, input.lines().count());
+            }
+        }
+
+        let input = "a1b\nnothing\n2c3\n\n   \nx\n\n \n";
+        let digits = Decoder::new(&[]).unwrap();
+        for chunk_size in [1, 5, 64] {
+            let zeroed = digits.calibrate_reader(input.as_bytes(), Missing::Zero, chunk_size);
+            assert_eq!(
+                zeroed.unwrap(),
+                digits.calibrate(input, Missing::Zero).unwrap()
+            );
+            let err = digits
+                .calibrate_reader(input.as_bytes(), Missing::Error, chunk_size)
+                .unwrap_err();
+            assert_eq!(err.to_string(), "no digits on lines 2, 4, 5, 6");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "no digits on line 2")]
+    fn test_solution_panics_on_missing_digits() {
+        solution1("1\nnone");
+    }
+
+    /// An endless reader of `pattern` repeated.
+    struct Repeat(&'static [u8], usize);
+
+    impl Read for Repeat {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            for b in buf.iter_mut() {
+                *b = self.0[self.1];
+                self.1 = (self.1 + 1) % self.0.len();
+            }
+            Ok(buf.len())
+        }
+    }
+
+    #[test]
+    #[ignore = "streams 130 MB, which takes a while without optimisations"]
+    fn test_calibrate_reader_past_u32() {
+        let lines = u32::MAX as u64 / 99 + 1;
+        let reader = Repeat(b"99\n", 0).take(lines * 3);
+        let calibration = Decoder::new(&[])
+            .unwrap()
+            .calibrate_reader(reader, Missing::Error, CHUNK_SIZE)
+            .unwrap();
+        assert_eq!(calibration.sum, lines * 99);
+        assert!(calibration.sum > u32::MAX as u64);
+    }
+
+    #[test]
+    fn test_decoder_rejects_bad_tokens() {
+        assert_eq!(
+            Decoder::new(&[("ten", 10)]).unwrap_err(),
+            "token `ten` maps to 10, not a digit 0-9"
+        );
+        assert_eq!(
+            Decoder::new(&[("one", 1), ("", 0)]).unwrap_err(),
+            "empty token"
+        );
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let (input1, input2) = (
+            std::fs::read_to_string("./data/1t1.txt").unwrap(),
+            std::fs::read_to_string("./data/1t2.txt").unwrap(),
+        );
+        assert_deterministic(|| (solution1(&input1), solution2(&input2)));
+    }
+}
@@ -0,0 +1,116 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::checked;
+use std::collections::HashMap;
+
+fn line_solve(
+    dots: &[char],
+    blocks: &[usize],
+    map: &mut HashMap<(usize, usize, usize), u64>,
+    i: usize,
+    bi: usize,
+    current: usize,
+) -> u64 {
+    let idx = (i, bi, current);
+
+    if let Some(v) = map.get(&idx) {
+        return *v;
+    }
+
+    if i == dots.len() {
+        if (bi == blocks.len() && current == 0) || (bi == blocks.len() - 1 && current == blocks[bi])
+        {
+            return 1;
+        } else {
+            return 0;
+        }
+    }
+
+    let mut sum = 0;
+    for c in ['.', '#'] {
+        if dots[i] == '?' || dots[i] == c {
+            let arrangements = if c == '.' && current == 0 {
+                line_solve(dots, blocks, map, i + 1, bi, 0)
+            } else if c == '.' && current > 0 && bi < blocks.len() && blocks[bi] == current {
+                line_solve(dots, blocks, map, i + 1, bi + 1, 0)
+            } else if c == '#' {
+                line_solve(dots, blocks, map, i + 1, bi, current + 1)
+            } else {
+                0
+            };
+            sum = checked::add(12, "arrangements", sum, arrangements);
+        }
+    }
+    map.insert(idx, sum);
+
+    sum
+}
+
+/// Counts the ways the `?` springs in a row can be filled in so that the damaged
+/// (`#`) springs form exactly the given groups.
+pub fn arrangements(springs: &str, groups: &[usize]) -> u64 {
+    let dots: Vec<char> = springs.chars().collect();
+    let mut map = HashMap::new();
+    let score = line_solve(&dots, groups, &mut map, 0, 0, 0);
+    tracing::trace!(springs, ?groups, score, memo = map.len(), "arrangements");
+    score
+}
+
+pub fn solution(inp: &str, p2: bool) -> Answer {
+    let mut sum: u64 = 0;
+
+    for line in inp.lines() {
+        cancel::check();
+        let (dots, blocks) = {
+            let (dots, blocks) = line.trim().split_once(' ').unwrap();
+            if p2 {
+                (
+                    format!("{}?{}?{}?{}?{}", dots, dots, dots, dots, dots),
+                    format!("{},{},{},{},{}", blocks, blocks, blocks, blocks, blocks),
+                )
+            } else {
+                (dots.to_string(), blocks.to_string())
+            }
+        };
+
+        let blocks: Vec<usize> = blocks.split(',').map(|x| x.parse().unwrap()).collect();
+        let score = arrangements(&dots, &blocks);
+
+        sum = checked::add(12, "sum of arrangements", sum, score);
+    }
+    sum.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+
+    const CASE: &str = r#"???.### 1,1,3
+    .??..??...?##. 1,1,3
+    ?#?#?#?#?#?#?#? 1,3,1,6
+    ????.#...#... 4,1,1
+    ????.######..#####. 1,6,5
+    ?###???????? 3,2,1"#;
+
+    #[test]
+    fn test_solution_1() {
+        assert_eq!(solution(CASE, false), 21);
+    }
+
+    #[test]
+    fn test_solution_2() {
+        assert_eq!(solution(CASE, true), 525152);
+    }
+
+    #[test]
+    fn test_arrangements() {
+        assert_eq!(arrangements("???.###", &[1, 1, 3]), 1);
+        assert_eq!(arrangements("?###????????", &[3, 2, 1]), 10);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        assert_deterministic(|| (solution(CASE, false), solution(CASE, true)));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::answer::Answer;
+use crate::explain::{self, Axis, Record};
+use crate::utils;
+
+#[allow(clippy::needless_range_loop)]
+pub fn solution(inp: &str, p2: bool) -> Answer {
+    let mut sum = 0;
+    let bad_comp = if p2 { 1 } else { 0 };
+
+    for (pattern, grid_str) in utils::blocks(inp).enumerate() {
+        let grid: Vec<Vec<char>> = grid_str
+            .lines()
+            .map(|line| line.trim().chars().collect())
+            .collect();
+
+        let row_size = grid.len();
+        let col_size = grid[0].len();
+
+        // vertical
+        for c in 0..(col_size - 1) {
+            let mut imperfect = 0;
+            for dc in 0..col_size {
+                let left: i32 = c as i32 - dc as i32;
+                let right: i32 = c as i32 + dc as i32 + 1;
+                if 0 <= left && left < right && right < col_size as i32 {
+                    for r in 0..row_size {
+                        if grid[r][left as usize] != grid[r][right as usize] {
+                            imperfect += 1;
+                        }
+                    }
+                }
+            }
+            if imperfect == bad_comp {
+                explain::record(|| Record::Reflection {
+                    pattern: pattern + 1,
+                    axis: Axis::Vertical,
+                    index: c + 1,
+                });
+                sum += c + 1;
+            }
+        }
+
+        // horizontal
+        for r in 0..(row_size - 1) {
+            let mut imperfect = 0;
+            for dr in 0..row_size {
+                let top: i32 = r as i32 - dr as i32;
+                let bottom: i32 = r as i32 + dr as i32 + 1;
+                if 0 <= top && top < bottom && bottom < row_size as i32 {
+                    for c in 0..col_size {
+                        if grid[top as usize][c] != grid[bottom as usize][c] {
+                            imperfect += 1;
+                        }
+                    }
+                }
+            }
+            if imperfect == bad_comp {
+                explain::record(|| Record::Reflection {
+                    pattern: pattern + 1,
+                    axis: Axis::Horizontal,
+                    index: r + 1,
+                });
+                sum += 100 * (r + 1);
+            }
+        }
+    }
+
+    sum.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+
+    const CASE: &str = r#"#.##..##.
+    ..#.##.#.
+    ##......#
+    ##......#
+    ..#.##.#.
+    ..##..##.
+    #.#.##.#.
+
+    #...##..#
+    #....#..#
+    ..##..###
+    #####.##.
+    #####.##.
+    ..##..###
+    #....#..#"#;
+
+    #[test]
+    fn test_solution_1() {
+        assert_eq!(solution(CASE, false), 405);
+    }
+
+    #[test]
+    fn test_solution_2() {
+        assert_eq!(solution(CASE, true), 400);
+    }
+
+    #[test]
+    fn test_explain_reflections() {
+        let (_, records) = explain::collect(|| solution(CASE, false));
+        assert_eq!(
+            records,
+            vec![
+                Record::Reflection {
+                    pattern: 1,
+                    axis: Axis::Vertical,
+                    index: 5
+                },
+                Record::Reflection {
+                    pattern: 2,
+                    axis: Axis::Horizontal,
+                    index: 4
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deterministic() {
+        assert_deterministic(|| (solution(CASE, false), solution(CASE, true)));
+    }
+}
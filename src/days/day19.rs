@@ -0,0 +1,425 @@
+use crate::answer::Answer;
+use crate::checked;
+use crate::repl;
+use crate::utils;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone)]
+enum Comparison {
+    Lesser,
+    Greater,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Categ {
+    X,
+    M,
+    A,
+    S,
+}
+
+#[derive(Debug, Clone)]
+enum Rule {
+    Comparison {
+        field: Categ,
+        comparison: Comparison,
+        value: u32,
+        destination: String,
+    },
+    Default {
+        destination: String,
+    },
+}
+
+#[derive(Default, Debug, Copy, Clone)]
+struct Shape {
+    x: u32,
+    m: u32,
+    a: u32,
+    s: u32,
+}
+
+#[derive(Default, Debug, Copy, Clone)]
+struct ShapeRange {
+    x: (u32, u32),
+    m: (u32, u32),
+    a: (u32, u32),
+    s: (u32, u32),
+}
+
+fn calc_size(ranges: &ShapeRange) -> u64 {
+    [ranges.x, ranges.m, ranges.a, ranges.s]
+        .iter()
+        .fold(1, |out, r| {
+            checked::mul(19, "calc_size", out, r.1 as u64 - r.0 as u64 + 1)
+        })
+}
+
+/// Each workflow's rules by its name.
+type Workflows = HashMap<String, Vec<Rule>>;
+
+#[tracing::instrument(name = "parse", level = "debug", skip_all)]
+fn parse_input(input: &str) -> Result<(Workflows, Vec<Shape>), String> {
+    let (rule, shape) = utils::blocks(input)
+        .collect_tuple()
+        .ok_or("expected workflows and parts separated by a blank line")?;
+
+    let rules_out: Workflows = rule
+        .lines()
+        .map(|line| {
+            let (name, rule) = line
+                .split_once('{')
+                .and_then(|(name, rule)| Some((name, rule.split_once('}')?.0)))
+                .ok_or_else(|| format!("expected `name{{rules}}`, got `{}`", line))?;
+            let rules = rule.split(',').map(str::parse).collect::<Result<_, _>>()?;
+            Ok((name.to_string(), rules))
+        })
+        .collect::<Result<_, String>>()?;
+
+    let shapes = shape.lines().map(str::parse).collect::<Result<_, _>>()?;
+
+    Ok((rules_out, shapes))
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    /// Parses `s<1351:px`, or a bare destination for the default rule.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((condition, destination)) = s.split_once(':') else {
+            return Ok(Rule::Default {
+                destination: s.to_string(),
+            });
+        };
+        let mut chars = condition.chars();
+        let field = match chars.next() {
+            Some('x') => Categ::X,
+            Some('m') => Categ::M,
+            Some('a') => Categ::A,
+            Some('s') => Categ::S,
+            _ => return Err(format!("unknown field in rule `{}`", s)),
+        };
+        let comparison = match chars.next() {
+            Some('<') => Comparison::Lesser,
+            Some('>') => Comparison::Greater,
+            _ => return Err(format!("unknown comparison in rule `{}`", s)),
+        };
+        let value = chars
+            .as_str()
+            .parse()
+            .map_err(|_| format!("bad value in rule `{}`", s))?;
+        Ok(Rule::Comparison {
+            field,
+            comparison,
+            value,
+            destination: destination.to_string(),
+        })
+    }
+}
+
+impl FromStr for Shape {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut x = Shape::default();
+        for part in s.trim().trim_matches('{').trim_matches('}').split(',') {
+            let (field, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("expected `field=value`, got `{}`", part))?;
+            let value = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("bad value `{}`", value))?;
+            match field.trim() {
+                "x" => x.x = value,
+                "m" => x.m = value,
+                "a" => x.a = value,
+                "s" => x.s = value,
+                field => return Err(format!("unknown field `{}`", field)),
+            }
+        }
+        Ok(x)
+    }
+}
+
+impl fmt::Display for Categ {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Categ::X => "x",
+            Categ::M => "m",
+            Categ::A => "a",
+            Categ::S => "s",
+        })
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Comparison::Lesser => "<",
+            Comparison::Greater => ">",
+        })
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::Comparison {
+                field,
+                comparison,
+                value,
+                destination,
+            } => write!(f, "{}{}{}:{}", field, comparison, value, destination),
+            Rule::Default { destination } => write!(f, "{}", destination),
+        }
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{x={},m={},a={},s={}}}", self.x, self.m, self.a, self.s)
+    }
+}
+
+impl Shape {
+    fn get(&self, field: &Categ) -> u32 {
+        match field {
+            Categ::X => self.x,
+            Categ::M => self.m,
+            Categ::A => self.a,
+            Categ::S => self.s,
+        }
+    }
+}
+
+impl ShapeRange {
+    fn get_mut(&mut self, field: &Categ) -> &mut (u32, u32) {
+        match field {
+            Categ::X => &mut self.x,
+            Categ::M => &mut self.m,
+            Categ::A => &mut self.a,
+            Categ::S => &mut self.s,
+        }
+    }
+}
+
+/// The workflows a shape passes through from `in`, ending with `A` or `R`.
+fn route<'a>(rules: &'a HashMap<String, Vec<Rule>>, shape: &Shape) -> Vec<&'a str> {
+    let mut workflow = "in";
+    let mut path = vec![workflow];
+
+    while workflow != "A" && workflow != "R" {
+        let current_workflow = rules.get(workflow).unwrap();
+        for rule in current_workflow {
+            match rule {
+                Rule::Comparison {
+                    field,
+                    comparison,
+                    value,
+                    destination,
+                } => {
+                    let val = shape.get(field);
+                    if match comparison {
+                        Comparison::Lesser => val < *value,
+                        Comparison::Greater => val > *value,
+                    } {
+                        workflow = destination;
+                        break;
+                    }
+                }
+                Rule::Default { destination } => {
+                    workflow = destination;
+                    break;
+                }
+            }
+        }
+        path.push(workflow);
+    }
+
+    path
+}
+
+pub fn solution1(inp: &str) -> Answer {
+    let (rules, shapes) = parse_input(inp).unwrap();
+    let mut out = 0;
+
+    for shape in shapes {
+        if route(&rules, &shape).last() == Some(&"A") {
+            out += shape.x + shape.m + shape.a + shape.s;
+        }
+    }
+
+    out.into()
+}
+
+fn solve_b(rules: &HashMap<String, Vec<Rule>>, mut range: ShapeRange, map: &str) -> u64 {
+    let mut out = 0;
+
+    let mut common = |range: ShapeRange, destination: &str| {
+        if destination == "A" {
+            out = checked::add(19, "accepted combinations", out, calc_size(&range));
+        } else if destination != "R" {
+            out = checked::add(
+                19,
+                "accepted combinations",
+                out,
+                solve_b(rules, range, destination),
+            );
+        }
+    };
+
+    for rule in rules.get(map).unwrap() {
+        match rule {
+            Rule::Comparison {
+                field,
+                comparison,
+                value,
+                destination,
+            } => {
+                let mut new_range = range;
+                let val = new_range.get_mut(field);
+                let rng = range.get_mut(field);
+
+                match comparison {
+                    Comparison::Greater if val.1 > *value => {
+                        val.0 = val.0.max(*value + 1);
+                        rng.1 = rng.1.min(*value);
+                    }
+                    Comparison::Lesser if val.0 < *value => {
+                        val.1 = val.1.min(*value - 1);
+                        rng.0 = rng.0.max(*value);
+                    }
+                    _ => continue,
+                }
+
+                common(new_range, destination);
+            }
+            Rule::Default { destination } => common(range, destination),
+        }
+    }
+
+    out
+}
+
+pub fn solution2(inp: &str) -> Answer {
+    let (rules, _) = parse_input(inp).unwrap();
+    let range = ShapeRange {
+        x: (1, 4000),
+        m: (1, 4000),
+        a: (1, 4000),
+        s: (1, 4000),
+    };
+    solve_b(&rules, range, "in").into()
+}
+
+/// `aoc repl 19`: walks shapes through the workflows.
+pub struct Session {
+    rules: HashMap<String, Vec<Rule>>,
+    shapes: Vec<Shape>,
+}
+
+impl Session {
+    pub fn new(input: &str) -> Result<Self, String> {
+        let (rules, shapes) = parse_input(input)?;
+        Ok(Session { rules, shapes })
+    }
+}
+
+impl repl::Session for Session {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("workflows", "list the workflow names"),
+            ("workflow <name>", "show a workflow's rules"),
+            ("parts", "list the parts with where they end up"),
+            (
+                "route {x=..,m=..,a=..,s=..}",
+                "trace a part through the workflows",
+            ),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Result<String, String> {
+        match command {
+            "workflows" => Ok(self.rules.keys().sorted().join(" ")),
+            "workflow" => {
+                let rules = self
+                    .rules
+                    .get(args)
+                    .ok_or_else(|| format!("no workflow `{}`", args))?;
+                Ok(format!("{}{{{}}}", args, rules.iter().join(",")))
+            }
+            "parts" => Ok(self
+                .shapes
+                .iter()
+                .map(|shape| format!("{} -> {}", shape, route(&self.rules, shape).last().unwrap()))
+                .join("\n")),
+            "route" => {
+                let shape: Shape = args.parse()?;
+                Ok(route(&self.rules, &shape).join(" -> "))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+    use crate::repl::Session as _;
+
+    const CASE: &str = r#"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}"#;
+
+    #[test]
+    fn test_solution_1() {
+        assert_eq!(solution1(CASE), 19114);
+    }
+
+    #[test]
+    fn test_solution_2() {
+        assert_eq!(solution2(CASE), 167409079868000_u64);
+    }
+
+    #[test]
+    fn test_session() {
+        let mut session = Session::new(CASE).unwrap();
+        assert_eq!(session.run("workflow", "in").unwrap(), "in{s<1351:px,qqz}");
+        assert_eq!(
+            session
+                .run("route", "{x=787,m=2655,a=1222,s=2876}")
+                .unwrap(),
+            "in -> qqz -> qs -> lnx -> A"
+        );
+        assert!(session
+            .run("parts", "")
+            .unwrap()
+            .starts_with("{x=787,m=2655,a=1222,s=2876} -> A\n{x=1679,m=44,a=2067,s=496} -> R"));
+        assert!(session.run("route", "{x=1,q=2}").is_err());
+        assert!(session.run("workflow", "zz").is_err());
+    }
+
+    #[test]
+    fn test_deterministic() {
+        assert_deterministic(|| (solution1(CASE), solution2(CASE)));
+    }
+}
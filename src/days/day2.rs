@@ -0,0 +1,284 @@
+use crate::answer::Answer;
+use crate::checked;
+use rayon::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
+
+/// A cube colour. Any name goes, not just red, green and blue.
+pub type Colour = String;
+
+/// Cube counts per colour: what one draw showed, or what a bag holds. A colour that
+/// is not in the map has no cubes.
+pub type Cubes = HashMap<Colour, u32>;
+
+/// The bag of the puzzle's first part.
+pub const PUZZLE_BAG: &[(&str, u32)] = &[("red", 12), ("green", 13), ("blue", 14)];
+
+/// The colours whose minimum counts multiply into the puzzle's power.
+pub const PUZZLE_COLOURS: &[&str] = &["red", "green", "blue"];
+
+/// Cubes from `(colour, count)` pairs.
+pub fn cubes(counts: &[(&str, u32)]) -> Cubes {
+    counts
+        .iter()
+        .map(|&(colour, n)| (colour.to_string(), n))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Cubes>,
+}
+
+impl FromStr for Game {
+    type Err = String;
+
+    /// Parses `Game 1: 3 blue, 4 red; 1 red, 2 green`. A colour named twice in a
+    /// draw counts both times.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, draws) = s
+            .trim()
+            .strip_prefix("Game ")
+            .and_then(|s| s.split_once(':'))
+            .ok_or_else(|| format!("expected `Game <id>: <draws>`, got `{}`", s))?;
+        let id = id.parse().map_err(|_| format!("bad game id `{}`", id))?;
+        let draws = draws
+            .split(';')
+            .map(|draw| {
+                let mut cubes = Cubes::new();
+                for seg in draw.split(',') {
+                    let (n, colour) = seg
+                        .trim()
+                        .split_once(' ')
+                        .ok_or_else(|| format!("expected `<count> <colour>`, got `{}`", seg))?;
+                    let n: u32 = n.parse().map_err(|_| format!("bad count `{}`", n))?;
+                    *cubes.entry(colour.trim().to_string()).or_default() += n;
+                }
+                Ok(cubes)
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(Game { id, draws })
+    }
+}
+
+impl Game {
+    /// Whether every draw could have come out of `bag`.
+    pub fn possible_with(&self, bag: &Cubes) -> bool {
+        self.draws.iter().all(|draw| {
+            draw.iter()
+                .all(|(colour, &n)| n <= bag.get(colour).copied().unwrap_or(0))
+        })
+    }
+
+    /// The fewest cubes of each colour seen that make the game possible.
+    pub fn minimum_bag(&self) -> Cubes {
+        let mut bag = Cubes::new();
+        for (colour, &n) in self.draws.iter().flatten() {
+            let max = bag.entry(colour.clone()).or_default();
+            *max = (*max).max(n);
+        }
+        bag
+    }
+}
+
+/// The product of the bag's counts of `colours`, 0 if it has none of one of them.
+pub fn power<'a>(bag: &Cubes, colours: impl IntoIterator<Item = &'a str>) -> u64 {
+    colours.into_iter().fold(1, |acc, colour| {
+        let n = bag.get(colour).copied().unwrap_or(0);
+        checked::mul(2, "power", acc, n as u64)
+    })
+}
+
+/// The games one bag allows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Possible {
+    /// Their ids, ascending.
+    pub ids: Vec<u32>,
+    pub sum: u64,
+}
+
+/// Answers which games are possible for many bags without reparsing. Each game is
+/// reduced once to its minimum bag. For every colour, the games are also kept
+/// sorted by how many cubes of that colour they need. A query binary-searches
+/// each colour for the games its count allows. Only the smallest of those sets
+/// is then checked against the remaining colours.
+pub struct Feasibility {
+    ids: Vec<u32>,
+    colours: Vec<Colour>,
+    /// Per game, the cubes it needs of each colour, indexed like `colours`.
+    needs: Vec<Vec<u32>>,
+    /// Per colour, `(needed, game)` in ascending order.
+    by_colour: Vec<Vec<(u32, usize)>>,
+}
+
+impl Feasibility {
+    pub fn new(games: &[Game]) -> Self {
+        let minima: Vec<_> = games.iter().map(Game::minimum_bag).collect();
+        let colours: Vec<Colour> = minima
+            .iter()
+            .flat_map(|bag| bag.keys().cloned())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let needs: Vec<Vec<u32>> = minima
+            .iter()
+            .map(|bag| {
+                colours
+                    .iter()
+                    .map(|colour| bag.get(colour).copied().unwrap_or(0))
+                    .collect()
+            })
+            .collect();
+        let by_colour = (0..colours.len())
+            .map(|c| {
+                let mut order: Vec<_> = needs.iter().map(|need| need[c]).zip(0..).collect();
+                order.sort_unstable();
+                order
+            })
+            .collect();
+        Feasibility {
+            ids: games.iter().map(|game| game.id).collect(),
+            colours,
+            needs,
+            by_colour,
+        }
+    }
+
+    /// The games possible with `bag`. Colours no game draws are ignored.
+    pub fn query(&self, bag: &Cubes) -> Possible {
+        let limits: Vec<u32> = self
+            .colours
+            .iter()
+            .map(|colour| bag.get(colour).copied().unwrap_or(0))
+            .collect();
+        // the games within the limit of the most selective colour
+        let candidates: Box<dyn Iterator<Item = usize>> = match (0..self.colours.len())
+            .map(|c| {
+                let allowed = &self.by_colour[c];
+                &allowed[..allowed.partition_point(|&(need, _)| need <= limits[c])]
+            })
+            .min_by_key(|allowed| allowed.len())
+        {
+            Some(allowed) => Box::new(allowed.iter().map(|&(_, game)| game)),
+            None => Box::new(0..self.ids.len()),
+        };
+
+        let mut ids: Vec<u32> = candidates
+            .filter(|&game| self.needs[game].iter().zip(&limits).all(|(n, l)| n <= l))
+            .map(|game| self.ids[game])
+            .collect();
+        ids.sort_unstable();
+        let sum = ids.iter().map(|&id| id as u64).sum();
+        Possible { ids, sum }
+    }
+
+    /// `query` for each bag, in parallel.
+    pub fn query_all(&self, bags: &[Cubes]) -> Vec<Possible> {
+        bags.par_iter().map(|bag| self.query(bag)).collect()
+    }
+}
+
+#[tracing::instrument(name = "parse", level = "debug", skip_all)]
+pub fn parse(input: &str) -> Vec<Game> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+pub fn solution1(input: &str) -> Answer {
+    let feasibility = Feasibility::new(&parse(input));
+    feasibility.query(&cubes(PUZZLE_BAG)).sum.into()
+}
+
+pub fn solution2(input: &str) -> Answer {
+    let games = parse(input);
+    let powers = games
+        .iter()
+        .map(|game| power(&game.minimum_bag(), PUZZLE_COLOURS.iter().copied()));
+    checked::sum(2, "sum of powers", powers).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+
+    #[test]
+    fn test_solution1() {
+        let sol = solution1(&std::fs::read_to_string("./data/2t.txt").unwrap());
+        assert_eq!(sol, 8);
+    }
+
+    #[test]
+    fn test_solution2() {
+        let sol = solution2(&std::fs::read_to_string("./data/2t.txt").unwrap());
+        assert_eq!(sol, 2286);
+    }
+
+    #[test]
+    fn test_game() {
+        let game: Game = "Game 7: 3 blue, 4 red; 1 teal, 2 red, 1 teal; 5 blue"
+            .parse()
+            .unwrap();
+        assert_eq!(game.id, 7);
+        assert_eq!(game.draws[1], cubes(&[("teal", 2), ("red", 2)]));
+        assert_eq!(
+            game.minimum_bag(),
+            cubes(&[("blue", 5), ("red", 4), ("teal", 2)])
+        );
+
+        assert!(!game.possible_with(&cubes(PUZZLE_BAG)));
+        assert!(game.possible_with(&cubes(&[("blue", 5), ("red", 4), ("teal", 2)])));
+        assert!(!game.possible_with(&cubes(&[("blue", 5), ("red", 4), ("teal", 1)])));
+
+        let bag = game.minimum_bag();
+        assert_eq!(power(&bag, PUZZLE_COLOURS.iter().copied()), 0);
+        assert_eq!(power(&bag, ["blue", "red", "teal"]), 40);
+        assert_eq!(power(&bag, []), 1);
+
+        assert!("Game x: 1 red".parse::<Game>().is_err());
+        assert!("Game 1: red".parse::<Game>().is_err());
+    }
+
+    #[test]
+    fn test_feasibility() {
+        let mut games = parse(&std::fs::read_to_string("./data/2t.txt").unwrap());
+        games.push("Game 9: 2 teal, 1 red".parse().unwrap());
+        let feasibility = Feasibility::new(&games);
+
+        let bags: Vec<Cubes> = [
+            PUZZLE_BAG,
+            &[],
+            &[("red", 20), ("green", 13), ("blue", 15), ("teal", 2)],
+            &[("red", 4), ("green", 3), ("blue", 6), ("ochre", 9)],
+            &[("red", 1), ("teal", 5)],
+        ]
+        .iter()
+        .map(|&counts| cubes(counts))
+        .collect();
+        let results = feasibility.query_all(&bags);
+        for (bag, result) in bags.iter().zip(&results) {
+            let ids: Vec<_> = games
+                .iter()
+                .filter(|game| game.possible_with(bag))
+                .map(|game| game.id)
+                .collect();
+            assert_eq!(result.ids, ids);
+            assert_eq!(result.sum, ids.iter().map(|&id| id as u64).sum::<u64>());
+        }
+        assert_eq!(results[0].ids, [1, 2, 5]);
+        assert_eq!(results[2].ids, [1, 2, 3, 4, 5, 9]);
+        assert_eq!(results[3].ids, [1, 2]);
+        assert!(results[1].ids.is_empty());
+
+        assert_eq!(
+            Feasibility::new(&[]).query(&cubes(PUZZLE_BAG)),
+            Possible::default()
+        );
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let input = std::fs::read_to_string("./data/2t.txt").unwrap();
+        assert_deterministic(|| (solution1(&input), solution2(&input)));
+    }
+}
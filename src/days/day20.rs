@@ -0,0 +1,395 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::repl;
+use itertools::Itertools;
+use num::integer::lcm;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Pulse {
+    High,
+    Low,
+}
+
+#[derive(Debug, Clone)]
+enum Module {
+    Broadcaster {
+        name: String,
+        outputs: Vec<String>,
+    },
+    FlipFlop {
+        name: String,
+        state: bool,
+        outputs: Vec<String>,
+    },
+    Conjunction {
+        name: String,
+        inputs: HashMap<String, Pulse>,
+        outputs: Vec<String>,
+    },
+}
+
+impl Module {
+    fn outputs(&self) -> &[String] {
+        match self {
+            Module::Broadcaster { outputs, .. } => outputs,
+            Module::FlipFlop { outputs, .. } => outputs,
+            Module::Conjunction { outputs, .. } => outputs,
+        }
+    }
+
+    fn send(&mut self, pulse: Pulse, from: &str, queue: &mut VecDeque<(String, String, Pulse)>) {
+        match self {
+            Module::Broadcaster { name, outputs } => {
+                for output in outputs {
+                    queue.push_back((name.to_string(), output.to_string(), pulse));
+                }
+            }
+
+            Module::FlipFlop {
+                name,
+                state,
+                outputs,
+            } => match pulse {
+                Pulse::High => {}
+                Pulse::Low => {
+                    let pulse = match state {
+                        false => Pulse::High,
+                        true => Pulse::Low,
+                    };
+
+                    for output in outputs {
+                        queue.push_back((name.to_string(), output.to_string(), pulse));
+                    }
+
+                    *state = !*state;
+                }
+            },
+
+            Module::Conjunction {
+                name,
+                inputs,
+                outputs,
+            } => {
+                inputs.insert(from.to_string(), pulse);
+
+                let pulse = match inputs.iter().all(|(_, pulse)| pulse == &Pulse::High) {
+                    true => Pulse::Low,
+                    false => Pulse::High,
+                };
+
+                for output in outputs {
+                    queue.push_back((name.clone(), output.clone(), pulse));
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Pulse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Pulse::High => "high",
+            Pulse::Low => "low",
+        })
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Module::Broadcaster { name, outputs } => {
+                write!(f, "{} -> {}", name, outputs.join(", "))
+            }
+            Module::FlipFlop {
+                name,
+                state,
+                outputs,
+            } => {
+                let state = if *state { "on" } else { "off" };
+                write!(f, "%{} -> {} ({})", name, outputs.join(", "), state)
+            }
+            Module::Conjunction {
+                name,
+                inputs,
+                outputs,
+            } => {
+                let inputs = inputs
+                    .iter()
+                    .sorted()
+                    .map(|(input, pulse)| format!("{}={}", input, pulse))
+                    .join(", ");
+                write!(f, "&{} -> {} ({})", name, outputs.join(", "), inputs)
+            }
+        }
+    }
+}
+
+#[tracing::instrument(name = "parse", level = "debug", skip_all)]
+fn parse_module_config(input: &str) -> Result<HashMap<String, Module>, String> {
+    let mut module_config = input
+        .lines()
+        .map(|line| {
+            let (label, outputs) = line
+                .trim()
+                .split_once(" -> ")
+                .ok_or_else(|| format!("expected `<module> -> <outputs>`, got `{}`", line))?;
+            let outputs = outputs.split(", ").map(String::from).collect::<Vec<_>>();
+
+            if label == "broadcaster" {
+                let name = label.to_string();
+                return Ok((name.clone(), Module::Broadcaster { name, outputs }));
+            }
+            let name = label.get(1..).unwrap_or_default().to_string();
+            if name.is_empty() {
+                return Err(format!("no module name in `{}`", line));
+            }
+            let module = match label.as_bytes()[0] {
+                b'%' => Module::FlipFlop {
+                    name: name.clone(),
+                    state: false,
+                    outputs,
+                },
+                b'&' => Module::Conjunction {
+                    name: name.clone(),
+                    inputs: HashMap::default(),
+                    outputs,
+                },
+                _ => return Err(format!("unknown module type in `{}`", line)),
+            };
+            Ok((name, module))
+        })
+        .collect::<Result<HashMap<_, _>, String>>()?;
+
+    for (name, module) in module_config.clone() {
+        for output in module.outputs() {
+            if let Some(Module::Conjunction { inputs, .. }) = module_config.get_mut(output) {
+                inputs.insert(name.clone(), Pulse::Low);
+            }
+        }
+    }
+    Ok(module_config)
+}
+
+/// Presses the button once, returning every `(from, to, pulse)` sent in order.
+fn press_button(module_config: &mut HashMap<String, Module>) -> Vec<(String, String, Pulse)> {
+    let mut sent = Vec::new();
+    let mut queue: VecDeque<(String, String, Pulse)> = VecDeque::default();
+    queue.push_back(("button".to_string(), "broadcaster".to_string(), Pulse::Low));
+
+    while let Some((from, target, pulse)) = queue.pop_front() {
+        if let Some(module) = module_config.get_mut(&target) {
+            module.send(pulse, &from, &mut queue);
+        }
+        sent.push((from, target, pulse));
+    }
+    sent
+}
+
+pub fn solution1(input: &str) -> Answer {
+    let mut module_config = parse_module_config(input).unwrap();
+    let (mut h_pulse, mut l_pulse) = (0, 0);
+
+    for _ in 1..=1000 {
+        for (_, _, pulse) in press_button(&mut module_config) {
+            match pulse {
+                Pulse::High => h_pulse += 1,
+                Pulse::Low => l_pulse += 1,
+            }
+        }
+    }
+
+    (h_pulse * l_pulse).into()
+}
+
+pub fn solution2(input: &str) -> Answer {
+    let mut module_config = parse_module_config(input).unwrap();
+    let mut tracker: HashMap<String, i64> = HashMap::default();
+
+    let mut presses = 1;
+
+    let previous = module_config
+        .iter()
+        .find(|(_, module)| module.outputs().contains(&String::from("rx")))
+        .map(|(module, _)| module.clone())
+        .unwrap();
+
+    loop {
+        cancel::check();
+        let mut queue: VecDeque<(String, String, Pulse)> = VecDeque::default();
+        queue.push_back(("button".to_string(), "broadcaster".to_string(), Pulse::Low));
+
+        while let Some((from, target, pulse)) = queue.pop_front() {
+            if let Some(module) = module_config.get_mut(&target) {
+                module.send(pulse, &from, &mut queue);
+
+                // The mg node is the only one that outputs to rx, and it is a conjunction.
+                // We track the iterations where its states alter and apply the Least Common Multiple (LCM) to them.
+                if let Module::Conjunction { name, inputs, .. } = &module {
+                    if name == &previous {
+                        for (input, last_pulse) in inputs {
+                            if !tracker.contains_key(input) && last_pulse == &Pulse::High {
+                                tracker.insert(input.to_string(), presses);
+                            }
+                        }
+
+                        if tracker.len() == inputs.len() {
+                            return tracker
+                                .values()
+                                .fold(1, |acc, press| lcm(acc, *press))
+                                .into();
+                        }
+                    }
+                }
+            }
+        }
+
+        presses += 1;
+    }
+}
+
+/// `aoc repl 20`: presses the button and inspects module state between presses.
+pub struct Session {
+    initial: HashMap<String, Module>,
+    modules: HashMap<String, Module>,
+    presses: usize,
+}
+
+impl Session {
+    pub fn new(input: &str) -> Result<Self, String> {
+        let modules = parse_module_config(input)?;
+        Ok(Session {
+            initial: modules.clone(),
+            modules,
+            presses: 0,
+        })
+    }
+}
+
+impl repl::Session for Session {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            (
+                "press [n]",
+                "press the button n times (default 1), counting pulses",
+            ),
+            ("trace", "press the button once, listing every pulse"),
+            ("module <name>", "show a module and its state"),
+            ("modules", "show every module"),
+            ("reset", "return every module to its initial state"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Result<String, String> {
+        match command {
+            "press" => {
+                let n: usize = match args {
+                    "" => 1,
+                    n => n.parse().map_err(|_| format!("bad count `{}`", n))?,
+                };
+                let (mut high, mut low) = (0, 0);
+                for _ in 0..n {
+                    for (_, _, pulse) in press_button(&mut self.modules) {
+                        match pulse {
+                            Pulse::High => high += 1,
+                            Pulse::Low => low += 1,
+                        }
+                    }
+                }
+                self.presses += n;
+                Ok(format!(
+                    "{} high, {} low ({} presses in total)",
+                    high, low, self.presses
+                ))
+            }
+            "trace" => {
+                self.presses += 1;
+                Ok(press_button(&mut self.modules)
+                    .iter()
+                    .map(|(from, to, pulse)| format!("{} -{}-> {}", from, pulse, to))
+                    .join("\n"))
+            }
+            "module" => self
+                .modules
+                .get(args)
+                .map(|module| module.to_string())
+                .ok_or_else(|| format!("no module `{}`", args)),
+            "modules" => Ok(self
+                .modules
+                .iter()
+                .sorted_by_key(|(name, _)| *name)
+                .map(|(_, module)| module.to_string())
+                .join("\n")),
+            "reset" => {
+                self.modules = self.initial.clone();
+                self.presses = 0;
+                Ok("reset".to_string())
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+    use crate::repl::Session as _;
+
+    const CASE: &str = r#"broadcaster -> a, b, c
+    %a -> b
+    %b -> c
+    %c -> inv
+    &inv -> a"#;
+
+    const CASE2: &str = r#"broadcaster -> a
+    %a -> inv, con
+    &inv -> b
+    %b -> con
+    &con -> output"#;
+
+    #[test]
+    fn test_solution_1() {
+        assert_eq!(solution1(CASE), 32000000);
+        assert_eq!(solution1(CASE2), 11687500);
+    }
+
+    #[test]
+    fn test_session() {
+        let mut session = Session::new(CASE2).unwrap();
+        assert_eq!(
+            session.run("module", "con").unwrap(),
+            "&con -> output (a=low, b=low)"
+        );
+        assert_eq!(
+            session
+                .run("trace", "")
+                .unwrap()
+                .lines()
+                .take(3)
+                .join(" | "),
+            "button -low-> broadcaster | broadcaster -low-> a | a -high-> inv"
+        );
+        assert_eq!(session.run("module", "a").unwrap(), "%a -> inv, con (on)");
+        assert_eq!(
+            session.run("press", "999").unwrap(),
+            "2746 high, 4246 low (1000 presses in total)"
+        );
+        session.run("reset", "").unwrap();
+        assert_eq!(session.run("module", "a").unwrap(), "%a -> inv, con (off)");
+        assert!(session.run("module", "rx").is_err());
+    }
+
+    #[test]
+    fn test_solution_2() {
+        let input = std::fs::read_to_string("./data/20.txt").unwrap();
+        assert_eq!(solution2(&input), 233283622908263_i64);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let input = std::fs::read_to_string("./data/20.txt").unwrap();
+        assert_deterministic(|| (solution1(CASE), solution2(&input)));
+    }
+}
@@ -0,0 +1,221 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::repl;
+use itertools::Itertools;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+
+/// Start and end corners; z start is always smaller than z stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Brick((i32, i32, i32), (i32, i32, i32));
+
+impl FromStr for Brick {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let corner = |c: &str| {
+            c.split(',')
+                .map(|x| x.trim().parse::<i32>().ok())
+                .collect::<Option<Vec<_>>>()
+                .and_then(|xs| xs.into_iter().collect_tuple())
+                .ok_or_else(|| format!("expected `x,y,z`, got `{}`", c))
+        };
+        let (s, e) = s
+            .trim()
+            .split_once('~')
+            .ok_or_else(|| format!("expected `x,y,z~x,y,z`, got `{}`", s))?;
+        Ok(Brick(corner(s)?, corner(e)?))
+    }
+}
+
+impl fmt::Display for Brick {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ((sx, sy, sz), (ex, ey, ez)) = (self.0, self.1);
+        write!(f, "{},{},{}~{},{},{}", sx, sy, sz, ex, ey, ez)
+    }
+}
+
+#[tracing::instrument(name = "parse", level = "debug", skip_all)]
+fn parse_bricks(input: &str) -> Result<Vec<Brick>, String> {
+    input.lines().map(str::parse).collect()
+}
+
+/// Where `br` comes to rest when dropped onto the settled bricks.
+fn drop_brick(supported_bricks: &mut [Brick], br: Brick) -> Brick {
+    if br.0 .2 == 1 {
+        //on floor
+        return br;
+    }
+
+    //find first collision when falling
+    supported_bricks.sort_by_key(|b| Reverse(b.1 .2));
+
+    for rb in supported_bricks.iter() {
+        if rb.0 .0 > br.1 .0 || rb.1 .0 < br.0 .0 || rb.0 .1 > br.1 .1 || rb.1 .1 < br.0 .1 {
+            //no collision, keep looking
+            continue;
+        } else {
+            //collision
+            let new_z_start = rb.1 .2 + 1;
+            let new_z_stop = new_z_start + br.1 .2 - br.0 .2;
+            return Brick(
+                (br.0 .0, br.0 .1, new_z_start),
+                (br.1 .0, br.1 .1, new_z_stop),
+            );
+        }
+    }
+
+    //found floor
+    Brick(
+        (br.0 .0, br.0 .1, 1),
+        (br.1 .0, br.1 .1, 1 + br.1 .2 - br.0 .2),
+    )
+}
+
+fn get_supported(bricks: &mut Vec<Brick>) -> Vec<Brick> {
+    bricks.sort_by_key(|b| Reverse(b.0 .2));
+    let mut supported_bricks = Vec::new();
+    while let Some(br) = bricks.pop() {
+        let new_brick = drop_brick(&mut supported_bricks, br);
+        supported_bricks.push(new_brick);
+    }
+    supported_bricks
+}
+
+pub fn solution(input: &str, pt2: bool) -> Answer {
+    let mut bricks = parse_bricks(input).unwrap();
+
+    //z start is always smaller than z stop
+    let supported_bricks = get_supported(&mut bricks);
+
+    let mut fixed = HashSet::new();
+    let mut brick_tree = HashMap::new();
+
+    for br in supported_bricks.clone() {
+        brick_tree.insert(br, (HashSet::new(), HashSet::new()));
+    }
+    for br in supported_bricks.clone() {
+        let mut lay_on = Vec::new();
+
+        for rb in supported_bricks.iter() {
+            if rb.0 .0 > br.1 .0 || rb.1 .0 < br.0 .0 || rb.0 .1 > br.1 .1 || rb.1 .1 < br.0 .1 {
+                //no overlap
+                continue;
+            } else {
+                //overlap, test resting
+                if rb.1 .2 == br.0 .2 - 1 {
+                    //resting
+                    lay_on.push(rb);
+
+                    brick_tree.get_mut(&br).unwrap().0.insert(rb);
+                    brick_tree.get_mut(rb).unwrap().1.insert(br);
+                }
+            }
+        }
+        if lay_on.len() == 1 {
+            fixed.insert(lay_on[0]);
+        }
+    }
+
+    if !pt2 {
+        return (supported_bricks.len() - fixed.len()).into();
+    }
+
+    let mut sum = 0;
+    for b in brick_tree.keys() {
+        cancel::check();
+        let mut count = 0;
+        let mut tmp_tree = brick_tree.clone();
+        let mut que = VecDeque::new();
+        que.push_back(*b);
+        while let Some(br) = que.pop_front() {
+            count += 1;
+            for supported_b in tmp_tree.get(&br).unwrap().1.clone() {
+                tmp_tree.get_mut(&supported_b).unwrap().0.remove(&br);
+                if tmp_tree.get(&supported_b).unwrap().0.is_empty() {
+                    que.push_back(supported_b);
+                }
+            }
+        }
+        sum += count - 1;
+    }
+    sum.into()
+}
+
+/// `aoc repl 22`: inspects the settled stack and drops extra bricks onto it.
+pub struct Session {
+    settled: Vec<Brick>,
+}
+
+impl Session {
+    pub fn new(input: &str) -> Result<Self, String> {
+        let mut settled = get_supported(&mut parse_bricks(input)?);
+        settled.sort_by_key(|b| (b.0 .2, b.0));
+        Ok(Session { settled })
+    }
+}
+
+impl repl::Session for Session {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("bricks", "list the settled bricks, lowest first"),
+            ("drop x,y,z~x,y,z", "show where a brick would come to rest"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Result<String, String> {
+        match command {
+            "bricks" => Ok(self.settled.iter().join("\n")),
+            "drop" => {
+                let brick: Brick = args.parse()?;
+                let mut settled = self.settled.clone();
+                Ok(drop_brick(&mut settled, brick).to_string())
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+    use crate::repl::Session as _;
+
+    const CASE: &str = r#"1,0,1~1,2,1
+    0,0,2~2,0,2
+    0,2,3~2,2,3
+    0,0,4~0,2,4
+    2,0,5~2,2,5
+    0,1,6~2,1,6
+    1,1,8~1,1,9"#;
+
+    #[test]
+    fn test_solution_1() {
+        assert_eq!(solution(CASE, false), 5);
+    }
+
+    #[test]
+    fn test_solution_2() {
+        assert_eq!(solution(CASE, true), 7);
+    }
+
+    #[test]
+    fn test_session() {
+        let mut session = Session::new(CASE).unwrap();
+        let bricks = session.run("bricks", "").unwrap();
+        assert_eq!(bricks.lines().last(), Some("1,1,5~1,1,6"));
+        assert_eq!(session.run("drop", "0,0,20~0,0,20").unwrap(), "0,0,4~0,0,4");
+        assert_eq!(session.run("drop", "5,5,20~5,5,21").unwrap(), "5,5,1~5,5,2");
+        assert!(session.run("drop", "1,2~3,4,5").is_err());
+    }
+
+    #[test]
+    fn test_deterministic() {
+        assert_deterministic(|| (solution(CASE, false), solution(CASE, true)));
+    }
+}
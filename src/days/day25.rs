@@ -0,0 +1,97 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::explain::{self, Record};
+use pathfinding::directed::bfs::bfs_reach;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+// Ordered maps keep the traversal order, and so the bridges found, independent of
+// hasher seeds.
+type Graph<'a> = BTreeMap<&'a str, BTreeSet<&'a str>>;
+
+pub fn solution(input: &str) -> Answer {
+    let mut graph = input
+        .lines()
+        .flat_map(|line| {
+            let (n, os) = line.trim().split_once(": ").unwrap();
+            os.split_whitespace()
+                .flat_map(move |o| vec![(n, o), (o, n)])
+        })
+        .fold(Graph::new(), |mut acc, (key, value)| {
+            acc.entry(key).or_default().insert(value);
+            acc
+        });
+
+    for _ in 0..3 {
+        let bridge = find_bridge(&graph);
+        graph.get_mut(&bridge.0).unwrap().remove(bridge.1);
+        graph.get_mut(&bridge.1).unwrap().remove(bridge.0);
+        explain::record(|| Record::CutEdge {
+            a: bridge.0.to_string(),
+            b: bridge.1.to_string(),
+        });
+    }
+    let gl = bfs_reach(*graph.keys().next().unwrap(), |n| graph[n].iter().copied()).count();
+    explain::record(|| Record::Components {
+        sizes: vec![gl, graph.len() - gl],
+    });
+
+    (gl * (graph.len() - gl)).into()
+}
+
+fn find_bridge<'a>(graph: &Graph<'a>) -> (&'a str, &'a str) {
+    let mut paths: HashMap<(&str, &str), usize> = HashMap::new();
+    for start in graph.keys().copied() {
+        cancel::check();
+        let mut to_see = VecDeque::new();
+        to_see.push_back(start);
+        let mut seen = HashSet::new();
+        seen.insert(start);
+        while let Some(node) = to_see.pop_front() {
+            for n in graph[&node].iter().copied() {
+                if !seen.contains(&n) {
+                    to_see.push_back(n);
+                    seen.insert(n);
+                    let edge = if n < node { (n, node) } else { (node, n) };
+                    *paths.entry(edge).or_default() += 1;
+                }
+            }
+        }
+    }
+    // ties go to the first edge in name order
+    paths
+        .into_iter()
+        .max_by_key(|&(edge, v)| (v, Reverse(edge)))
+        .unwrap()
+        .0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+
+    const CASE: &str = r#"jqt: rhn xhk nvd
+    rsh: frs pzl lsr
+    xhk: hfx
+    cmg: qnr nvd lhk bvb
+    rhn: xhk bvb hfx
+    bvb: xhk hfx
+    pzl: lsr hfx nvd
+    qnr: nvd
+    ntq: jqt hfx bvb xhk
+    nvd: lhk
+    lsr: lhk
+    rzs: qnr cmg lsr rsh
+    frs: qnr lhk lsr"#;
+
+    #[test]
+    fn test_solution_1() {
+        assert_eq!(solution(CASE), 54);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        assert_deterministic(|| solution(CASE));
+    }
+}
@@ -0,0 +1,366 @@
+use crate::answer::Answer;
+use crate::checked;
+use std::collections::HashMap;
+
+/// A number in the schematic, spanning columns `x..x + len` of row `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Part {
+    pub value: u64,
+    pub x: usize,
+    pub y: usize,
+    pub len: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol {
+    pub char: char,
+    pub x: usize,
+    pub y: usize,
+}
+
+/// Which characters count as symbols; anything else that is not a digit is blank.
+#[derive(Debug, Clone, Default)]
+pub enum SymbolSet {
+    /// Every character but `.` and digits, as in the puzzle.
+    #[default]
+    Any,
+    Only(Vec<char>),
+}
+
+impl SymbolSet {
+    pub fn contains(&self, c: char) -> bool {
+        match self {
+            SymbolSet::Any => c != '.' && !c.is_ascii_digit(),
+            SymbolSet::Only(chars) => chars.contains(&c),
+        }
+    }
+}
+
+/// What makes a symbol a gear: its character and how many parts it touches.
+#[derive(Debug, Clone)]
+pub struct GearRule {
+    pub symbol: char,
+    pub parts: usize,
+}
+
+impl Default for GearRule {
+    fn default() -> Self {
+        GearRule {
+            symbol: '*',
+            parts: 2,
+        }
+    }
+}
+
+/// Which cells around a cell are adjacent to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Kind {
+    /// Within `radius` steps of king moves, diagonals included.
+    #[default]
+    Moore,
+    /// Within `radius` steps of rook moves, i.e. by Manhattan distance.
+    VonNeumann,
+}
+
+/// What lies past the schematic's edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Wrap {
+    /// Nothing.
+    #[default]
+    None,
+    /// The opposite edge, as on a torus. The width is that of the longest line.
+    /// Numbers themselves never wrap onto the next line.
+    Torus,
+}
+
+/// The cells a part reaches: those adjacent to any of its digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Neighbourhood {
+    pub kind: Kind,
+    pub radius: usize,
+    pub wrap: Wrap,
+}
+
+impl Default for Neighbourhood {
+    /// The puzzle's: the eight cells around each digit.
+    fn default() -> Self {
+        Neighbourhood {
+            kind: Kind::Moore,
+            radius: 1,
+            wrap: Wrap::None,
+        }
+    }
+}
+
+impl Neighbourhood {
+    /// The cells other than its own that `part` reaches on a `w` by `h` grid. A cell
+    /// may come up more than once when the grid wraps.
+    fn around(self, part: Part, w: usize, h: usize) -> impl Iterator<Item = (usize, usize)> {
+        let r = self.radius as i64;
+        let (x0, x1, y) = (part.x as i64, (part.x + part.len) as i64 - 1, part.y as i64);
+        (y - r..=y + r)
+            .flat_map(move |cy| (x0 - r..=x1 + r).map(move |cx| (cx, cy)))
+            .filter(move |&(cx, cy)| {
+                let (dx, dy) = ((x0 - cx).max(cx - x1).max(0), (cy - y).abs());
+                let within = match self.kind {
+                    Kind::Moore => true,
+                    Kind::VonNeumann => dx + dy <= r,
+                };
+                within && (dx, dy) != (0, 0)
+            })
+            .filter_map(move |(cx, cy)| match self.wrap {
+                Wrap::None => (cx >= 0 && cy >= 0).then_some((cx as usize, cy as usize)),
+                Wrap::Torus => Some((
+                    cx.rem_euclid(w as i64) as usize,
+                    cy.rem_euclid(h as i64) as usize,
+                )),
+            })
+    }
+}
+
+/// The parts and symbols of a schematic, and which of them touch: a bipartite
+/// graph in which parts and symbols are referred to by their index, both in
+/// reading order. Every number is a part, touching a symbol or not.
+pub struct Schematic {
+    pub parts: Vec<Part>,
+    pub symbols: Vec<Symbol>,
+    part_symbols: Vec<Vec<usize>>,
+    symbol_parts: Vec<Vec<usize>>,
+    symbol_at: HashMap<(usize, usize), usize>,
+}
+
+impl Schematic {
+    #[tracing::instrument(name = "parse", level = "debug", skip_all)]
+    pub fn new(input: &str, symbol_set: &SymbolSet, neighbourhood: Neighbourhood) -> Self {
+        let mut parts = Vec::new();
+        let mut symbols = Vec::new();
+        let mut symbol_at = HashMap::new();
+        let mut width = 0;
+        for (y, line) in input.lines().enumerate() {
+            let mut number: Option<Part> = None;
+            for (x, c) in line.chars().enumerate() {
+                if let Some(digit) = c.to_digit(10) {
+                    let part = number.get_or_insert(Part {
+                        value: 0,
+                        x,
+                        y,
+                        len: 0,
+                    });
+                    part.value = checked::add(
+                        3,
+                        "part number",
+                        checked::mul(3, "part number", part.value, 10),
+                        digit as u64,
+                    );
+                    part.len += 1;
+                    continue;
+                }
+                parts.extend(number.take());
+                if symbol_set.contains(c) {
+                    symbol_at.insert((x, y), symbols.len());
+                    symbols.push(Symbol { char: c, x, y });
+                }
+            }
+            parts.extend(number);
+            width = width.max(line.chars().count());
+        }
+        let height = input.lines().count();
+
+        let mut part_symbols = vec![Vec::new(); parts.len()];
+        let mut symbol_parts = vec![Vec::new(); symbols.len()];
+        for (p, part) in parts.iter().enumerate() {
+            let near = &mut part_symbols[p];
+            near.extend(
+                neighbourhood
+                    .around(*part, width, height)
+                    .filter_map(|pos| symbol_at.get(&pos).copied()),
+            );
+            near.sort_unstable();
+            near.dedup();
+            for &s in near.iter() {
+                symbol_parts[s].push(p);
+            }
+        }
+        Schematic {
+            parts,
+            symbols,
+            part_symbols,
+            symbol_parts,
+            symbol_at,
+        }
+    }
+
+    /// The index of the symbol at `(x, y)`, if there is one.
+    pub fn symbol_at(&self, x: usize, y: usize) -> Option<usize> {
+        self.symbol_at.get(&(x, y)).copied()
+    }
+
+    /// The symbols touching part `part`.
+    pub fn symbols_of(&self, part: usize) -> impl Iterator<Item = &Symbol> {
+        self.part_symbols[part].iter().map(|&s| &self.symbols[s])
+    }
+
+    /// The parts touching symbol `symbol`.
+    pub fn parts_of(&self, symbol: usize) -> impl Iterator<Item = &Part> {
+        self.symbol_parts[symbol].iter().map(|&p| &self.parts[p])
+    }
+
+    /// The parts touching at least one symbol.
+    pub fn adjacent_parts(&self) -> impl Iterator<Item = &Part> {
+        (0..self.parts.len())
+            .filter(|&p| !self.part_symbols[p].is_empty())
+            .map(|p| &self.parts[p])
+    }
+
+    /// The parts touching a symbol drawn as `c`, each once.
+    pub fn parts_near(&self, c: char) -> impl Iterator<Item = &Part> {
+        (0..self.parts.len())
+            .filter(move |&p| self.symbols_of(p).any(|s| s.char == c))
+            .map(|p| &self.parts[p])
+    }
+
+    /// The indices of the symbols touching exactly `n` parts.
+    pub fn symbols_with(&self, n: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.symbols.len()).filter(move |&s| self.symbol_parts[s].len() == n)
+    }
+
+    /// The gears under `rule` with their parts.
+    pub fn gears(&self, rule: &GearRule) -> impl Iterator<Item = (&Symbol, Vec<&Part>)> {
+        let symbol = rule.symbol;
+        self.symbols_with(rule.parts)
+            .filter(move |&s| self.symbols[s].char == symbol)
+            .map(|s| (&self.symbols[s], self.parts_of(s).collect()))
+    }
+
+    /// The product of each gear's part numbers.
+    pub fn gear_ratios(&self, rule: &GearRule) -> impl Iterator<Item = u64> + '_ {
+        self.gears(rule).map(|(_, parts)| {
+            parts.iter().fold(1, |acc, part| {
+                checked::mul(3, "gear ratio", acc, part.value)
+            })
+        })
+    }
+}
+
+pub fn solution1(input: &str) -> Answer {
+    let schematic = Schematic::new(input, &SymbolSet::Any, Neighbourhood::default());
+    let values = schematic.adjacent_parts().map(|part| part.value);
+    checked::sum(3, "sum of part numbers", values).into()
+}
+
+pub fn solution2(input: &str) -> Answer {
+    let schematic = Schematic::new(input, &SymbolSet::Any, Neighbourhood::default());
+    let ratios = schematic.gear_ratios(&GearRule::default());
+    checked::sum(3, "sum of gear ratios", ratios).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+
+    #[test]
+    fn test_solution1() {
+        let sol = solution1(&std::fs::read_to_string("./data/3t.txt").unwrap());
+        assert_eq!(sol, 4361);
+    }
+
+    #[test]
+    fn test_solution2() {
+        let sol = solution2(&std::fs::read_to_string("./data/3t.txt").unwrap());
+        assert_eq!(sol, 467835);
+    }
+
+    #[test]
+    fn test_schematic() {
+        let input = std::fs::read_to_string("./data/3t.txt").unwrap();
+        let schematic = Schematic::new(&input, &SymbolSet::Any, Neighbourhood::default());
+        assert_eq!(schematic.parts.len(), 10);
+        assert_eq!(schematic.symbols.len(), 6);
+
+        let star = schematic.symbol_at(3, 1).unwrap();
+        let near: Vec<_> = schematic.parts_of(star).map(|p| p.value).collect();
+        assert_eq!(near, [467, 35]);
+        assert_eq!(
+            schematic.symbols_of(0).collect::<Vec<_>>(),
+            [&Symbol {
+                char: '*',
+                x: 3,
+                y: 1
+            }]
+        );
+        let stars: Vec<_> = schematic.parts_near('*').map(|p| p.value).collect();
+        assert_eq!(stars, [467, 35, 617, 755, 598]);
+        assert_eq!(schematic.symbols_with(1).count(), 4);
+        assert_eq!(schematic.adjacent_parts().count(), 8);
+
+        let lone = GearRule {
+            symbol: '*',
+            parts: 1,
+        };
+        assert_eq!(schematic.gear_ratios(&lone).collect::<Vec<_>>(), [617]);
+        let dollar = GearRule {
+            symbol: '$',
+            parts: 1,
+        };
+        assert_eq!(schematic.gear_ratios(&dollar).collect::<Vec<_>>(), [664]);
+
+        let only_hash = Schematic::new(
+            &input,
+            &SymbolSet::Only(vec!['#']),
+            Neighbourhood::default(),
+        );
+        let values: Vec<_> = only_hash.adjacent_parts().map(|p| p.value).collect();
+        assert_eq!(values, [633]);
+    }
+
+    fn sums(input: &str, kind: Kind, radius: usize, wrap: Wrap) -> (u64, u64, usize) {
+        let neighbourhood = Neighbourhood { kind, radius, wrap };
+        let schematic = Schematic::new(input, &SymbolSet::Any, neighbourhood);
+        let values = schematic.adjacent_parts().map(|part| part.value).sum();
+        let ratios = schematic.gear_ratios(&GearRule::default()).sum();
+        let star = schematic.symbol_at(3, 1).unwrap();
+        (values, ratios, schematic.parts_of(star).count())
+    }
+
+    #[test]
+    fn test_neighbourhoods() {
+        let input = std::fs::read_to_string("./data/3t.txt").unwrap();
+        let (moore, von_neumann) = (Kind::Moore, Kind::VonNeumann);
+
+        assert_eq!(sums(&input, moore, 1, Wrap::None), (4361, 467835, 2));
+        // 467 and 755 only touch their stars diagonally
+        assert_eq!(sums(&input, von_neumann, 1, Wrap::None), (2547, 0, 1));
+        assert_eq!(sums(&input, moore, 2, Wrap::None), (4533, 0, 3));
+        assert_eq!(sums(&input, von_neumann, 2, Wrap::None), (4419, 467835, 2));
+        assert_eq!(sums(&input, moore, 0, Wrap::None), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_wrap() {
+        let input = std::fs::read_to_string("./data/3t.txt").unwrap();
+        // nothing in the example touches across an edge at radius 1
+        assert_eq!(sums(&input, Kind::Moore, 1, Wrap::Torus), (4361, 467835, 2));
+        // the star in row 1 also reaches 664 and 598 across the top edge
+        assert_eq!(sums(&input, Kind::Moore, 2, Wrap::Torus), (4533, 0, 5));
+        assert_eq!(sums(&input, Kind::VonNeumann, 2, Wrap::Torus), (4533, 0, 3));
+
+        // a radius past the grid's size reaches every symbol, each counted once
+        let schematic = Schematic::new(
+            "1.\n.#",
+            &SymbolSet::Any,
+            Neighbourhood {
+                kind: Kind::Moore,
+                radius: 5,
+                wrap: Wrap::Torus,
+            },
+        );
+        assert_eq!(schematic.parts_of(0).count(), 1);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let input = std::fs::read_to_string("./data/3t.txt").unwrap();
+        assert_deterministic(|| (solution1(&input), solution2(&input)));
+    }
+}
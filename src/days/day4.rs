@@ -0,0 +1,316 @@
+use crate::answer::Answer;
+use crate::checked;
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+/// A scratchcard. Its numbers are below 128, so each side is a bitset in a `u128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Card {
+    pub id: u32,
+    pub winning: u128,
+    pub have: u128,
+}
+
+fn bitset(numbers: &str) -> Result<u128, String> {
+    numbers
+        .split_whitespace()
+        .try_fold(0, |set, n| match n.parse::<u32>() {
+            Ok(n) if n < 128 => Ok(set | 1 << n),
+            Ok(n) => Err(format!("card number {} is not below 128", n)),
+            Err(_) => Err(format!("bad card number `{}`", n)),
+        })
+}
+
+impl FromStr for Card {
+    type Err = String;
+
+    /// Parses `Card 1: 41 48 83 | 83 86 6`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, numbers) = s
+            .strip_prefix("Card")
+            .and_then(|s| s.split_once(':'))
+            .ok_or_else(|| format!("expected `Card <id>: <numbers>`, got `{}`", s))?;
+        let id = id.trim();
+        let id = id.parse().map_err(|_| format!("bad card id `{}`", id))?;
+        let (winning, have) = numbers
+            .split_once('|')
+            .ok_or_else(|| format!("expected `<winning> | <have>`, got `{}`", numbers))?;
+        Ok(Card {
+            id,
+            winning: bitset(winning)?,
+            have: bitset(have)?,
+        })
+    }
+}
+
+impl Card {
+    /// How many of the numbers we have are winning numbers.
+    pub fn matches(&self) -> u32 {
+        (self.winning & self.have).count_ones()
+    }
+}
+
+/// Card ids that do not run 1, 2, 3, ... in order, which copies are counted by.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BadIds {
+    /// Ids below the largest that are not listed.
+    pub missing: Vec<u32>,
+    /// Ids listed after a larger one.
+    pub out_of_order: Vec<u32>,
+    /// Ids listed more than once.
+    pub duplicate: Vec<u32>,
+}
+
+fn join(ids: &[u32]) -> String {
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl fmt::Display for BadIds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let problems: Vec<String> = [
+            ("missing cards", &self.missing),
+            ("cards out of order", &self.out_of_order),
+            ("duplicate cards", &self.duplicate),
+        ]
+        .into_iter()
+        .filter(|(_, ids)| !ids.is_empty())
+        .map(|(what, ids)| format!("{} {}", what, join(ids)))
+        .collect();
+        write!(f, "{}", problems.join("; "))
+    }
+}
+
+impl std::error::Error for BadIds {}
+
+fn check_ids(cards: &[Card]) -> Result<(), BadIds> {
+    let mut bad = BadIds::default();
+    let mut seen = HashSet::new();
+    let mut max = 0;
+    for card in cards {
+        if !seen.insert(card.id) {
+            bad.duplicate.push(card.id);
+        } else if card.id < max {
+            bad.out_of_order.push(card.id);
+        }
+        max = max.max(card.id);
+    }
+    bad.missing = (1..max).filter(|id| !seen.contains(id)).collect();
+    if bad == BadIds::default() {
+        Ok(())
+    } else {
+        Err(bad)
+    }
+}
+
+/// How a table of cards is scored.
+pub trait ScoringRule {
+    /// The table's total. Rules that depend on card order fail on bad ids.
+    fn score(&self, cards: &[Card]) -> Result<u64, BadIds>;
+}
+
+/// The numbers on `card` that win, ascending.
+fn winning_numbers(card: &Card) -> impl Iterator<Item = u32> {
+    let mut matched = card.winning & card.have;
+    std::iter::from_fn(move || {
+        (matched != 0).then(|| {
+            let n = matched.trailing_zeros();
+            matched &= matched - 1;
+            n
+        })
+    })
+}
+
+/// The puzzle's first rule: one point for the first match, doubled for each
+/// further one.
+pub struct Doubling;
+
+impl ScoringRule for Doubling {
+    fn score(&self, cards: &[Card]) -> Result<u64, BadIds> {
+        let points = cards
+            .iter()
+            .map(Card::matches)
+            .filter(|&matches| matches > 0)
+            .map(|matches| checked::pow(4, "2^(matches - 1)", 2u64, matches as usize - 1));
+        Ok(checked::sum(4, "sum of points", points))
+    }
+}
+
+/// One point per match.
+pub struct Linear;
+
+impl ScoringRule for Linear {
+    fn score(&self, cards: &[Card]) -> Result<u64, BadIds> {
+        Ok(cards.iter().map(|card| card.matches() as u64).sum())
+    }
+}
+
+/// Each winning number we have is worth its weight.
+pub struct Weighted<F>(pub F);
+
+impl<F: Fn(u32) -> u64> ScoringRule for Weighted<F> {
+    fn score(&self, cards: &[Card]) -> Result<u64, BadIds> {
+        let points = cards.iter().flat_map(winning_numbers).map(&self.0);
+        Ok(checked::sum(4, "sum of weights", points))
+    }
+}
+
+/// The puzzle's second rule, counting the cards held in the end, originals and
+/// copies. Card `i` wins one copy of each of the next `matches` cards per copy of
+/// it held, so a pass in id order has every card's count final before it is used.
+/// The default is the puzzle's rule, where cards past the end of the table are not
+/// won.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Copies {
+    /// The most of any one card that can be held; further copies are lost.
+    pub cap: Option<u64>,
+    /// Whether copies past the last card are of the first cards instead. Cards
+    /// already scratched, the winning card included, still gain them, but the late
+    /// copies win nothing.
+    pub wrap: bool,
+}
+
+impl ScoringRule for Copies {
+    fn score(&self, cards: &[Card]) -> Result<u64, BadIds> {
+        check_ids(cards)?;
+        let n = cards.len();
+        let held = |count: u64| self.cap.map_or(count, |cap| count.min(cap));
+        let mut copies = vec![held(1); n];
+        for (i, card) in cards.iter().enumerate() {
+            let count = copies[i];
+            let won = (i + 1..=i + card.matches() as usize)
+                .take_while(|&j| self.wrap || j < n)
+                .map(|j| j % n);
+            for j in won {
+                copies[j] = held(checked::add(4, "card copies", copies[j], count));
+            }
+        }
+        Ok(checked::sum(4, "total cards", copies))
+    }
+}
+
+fn total(rule: &dyn ScoringRule, input: &str) -> Answer {
+    match rule.score(&parse(input)) {
+        Ok(total) => total.into(),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[tracing::instrument(name = "parse", level = "debug", skip_all)]
+pub fn parse(input: &str) -> Vec<Card> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+pub fn solution1(input: &str) -> Answer {
+    total(&Doubling, input)
+}
+
+pub fn solution2(input: &str) -> Answer {
+    total(&Copies::default(), input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+
+    #[test]
+    fn test_solution1() {
+        let sol = solution1(&std::fs::read_to_string("./data/4t.txt").unwrap());
+        assert_eq!(sol, 13);
+    }
+
+    #[test]
+    fn test_solution2() {
+        let sol = solution2(&std::fs::read_to_string("./data/4t.txt").unwrap());
+        assert_eq!(sol, 30);
+    }
+
+    #[test]
+    fn test_card() {
+        let card: Card = "Card  12: 41 48 127 | 83  0 127 41".parse().unwrap();
+        assert_eq!(card.id, 12);
+        assert_eq!(card.have, 1 << 83 | 1 | 1 << 127 | 1 << 41);
+        assert_eq!(card.matches(), 2);
+
+        assert!("Card 1: 128 | 1".parse::<Card>().is_err());
+        assert!("Card 1: 1 2 3".parse::<Card>().is_err());
+        assert!("Card x: 1 | 2".parse::<Card>().is_err());
+    }
+
+    #[test]
+    fn test_copies() {
+        let cards = parse(&std::fs::read_to_string("./data/4t.txt").unwrap());
+        let count_cards = |cards: &[Card]| Copies::default().score(cards);
+        assert_eq!(count_cards(&cards), Ok(30));
+        // card 1 has four matches, but the table ends after card 3
+        assert_eq!(count_cards(&cards[..3]), Ok(1 + 2 + 4));
+        assert_eq!(count_cards(&[]), Ok(0));
+
+        let mut shuffled = cards.clone();
+        shuffled.swap(1, 3);
+        shuffled.remove(2);
+        shuffled.push(cards[0]);
+        let err = count_cards(&shuffled).unwrap_err();
+        assert_eq!(
+            err,
+            BadIds {
+                missing: vec![3],
+                out_of_order: vec![2],
+                duplicate: vec![1],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "missing cards 3; cards out of order 2; duplicate cards 1"
+        );
+    }
+
+    #[test]
+    fn test_scoring_rules() {
+        let cards = parse(&std::fs::read_to_string("./data/4t.txt").unwrap());
+        assert_eq!(Doubling.score(&cards), Ok(13));
+        assert_eq!(Linear.score(&cards), Ok(4 + 2 + 2 + 1));
+        // 48 + 83 + 86 + 17, 32 + 61, 1 + 21 and 84
+        assert_eq!(Weighted(|n| n as u64).score(&cards), Ok(433));
+        assert_eq!(Weighted(|_| 1).score(&cards), Linear.score(&cards));
+
+        let capped = Copies {
+            cap: Some(2),
+            wrap: false,
+        };
+        assert_eq!(capped.score(&cards), Ok(1 + 2 + 2 + 2 + 2 + 1));
+        let first = Copies {
+            cap: Some(1),
+            wrap: false,
+        };
+        assert_eq!(first.score(&cards), Ok(6));
+
+        // over four cards, card 1 wins a copy of itself, card 3 one of card 1 per
+        // copy held and card 4 the same
+        let wrapping = Copies {
+            cap: None,
+            wrap: true,
+        };
+        assert_eq!(Copies::default().score(&cards[..4]), Ok(1 + 2 + 4 + 8));
+        assert_eq!(wrapping.score(&cards[..4]), Ok(14 + 2 + 4 + 8));
+        assert_eq!(wrapping.score(&cards), Copies::default().score(&cards));
+        // over two cards, card 1's four matches go round twice, each pass won by its
+        // single original; card 2 then wins one more of each per copy held
+        assert_eq!(wrapping.score(&cards[..2]), Ok(6 + 6));
+
+        let mut shuffled = cards.clone();
+        shuffled.swap(0, 1);
+        assert!(wrapping.score(&shuffled).is_err());
+        assert_eq!(Doubling.score(&shuffled), Ok(13));
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let input = std::fs::read_to_string("./data/4t.txt").unwrap();
+        assert_deterministic(|| (solution1(&input), solution2(&input)));
+    }
+}
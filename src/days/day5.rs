@@ -0,0 +1,430 @@
+use crate::answer::Answer;
+use crate::utils;
+use std::str::FromStr;
+
+/// A `(start, length)` range of numbers.
+pub type Range = (u64, u64);
+
+#[derive(Debug)]
+struct Mapping {
+    dest_st: u64,
+    src_st: u64,
+    range_l: u64,
+}
+
+impl Mapping {
+    fn contains(&self, n: u64) -> bool {
+        n >= self.src_st && n - self.src_st < self.range_l
+    }
+
+    fn get(&self, n: u64) -> Option<u64> {
+        self.contains(n).then(|| self.dest_st + (n - self.src_st))
+    }
+
+    fn intersect(&self, n: Range) -> (Option<Range>, Vec<Range>) {
+        intersect_range((self.src_st, self.range_l), n)
+    }
+
+    fn conv(&self, n: Range) -> Range {
+        (self.dest_st + (n.0 - self.src_st), n.1)
+    }
+}
+
+impl FromStr for Mapping {
+    type Err = String;
+
+    /// Parses `50 98 2`: destination start, source start and length.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = s
+            .split_whitespace()
+            .map(|n| {
+                n.parse()
+                    .map_err(|_| format!("bad number `{}` in `{}`", n, s))
+            })
+            .collect::<Result<Vec<u64>, _>>()?;
+        let [dest_st, src_st, range_l] = numbers[..] else {
+            return Err(format!("expected `<dest> <src> <len>`, got `{}`", s));
+        };
+
+        Ok(Mapping {
+            dest_st,
+            src_st,
+            range_l,
+        })
+    }
+}
+
+fn intersect_range(target: Range, src_st: Range) -> (Option<Range>, Vec<Range>) {
+    let target_begin = target.0;
+    let target_range = target.1;
+    let target_end = target.0 + target.1 - 1;
+    let src_st_begin = src_st.0;
+    let src_st_end = src_st.0 + src_st.1 - 1;
+
+    match (
+        src_st_begin >= target_begin && src_st_begin <= target_end,
+        src_st_end >= target_begin && src_st_end <= target_end,
+    ) {
+        // src_st starts within target
+        (true, true) => (Some(src_st), vec![]),
+        // src_st ends outside target
+        (true, false) => (
+            Some((src_st_begin, target_range - (src_st_begin - target_begin))),
+            vec![(target_begin + target_range, (src_st_end) - (target_end))],
+        ),
+        // src_st begins before target but ends in target
+        (false, true) => (
+            Some((target_begin, src_st_end - target_begin + 1)),
+            vec![(src_st_begin, target_begin - src_st_begin)],
+        ),
+        // src_st contains target
+        (false, false) if src_st_begin < target_begin && src_st_end > target_end => (
+            Some(target),
+            vec![
+                (src_st_begin, target_begin - src_st_begin),
+                (target_begin + target_range, src_st_end - target_end),
+            ],
+        ),
+        _ => (None, vec![src_st]),
+    }
+}
+
+/// A map of every `u64` made of linear pieces: each piece adds its offset to the
+/// numbers from its start up to the next piece's start. The pieces are sorted and
+/// the first starts at 0, so finding a number's piece is a binary search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    pieces: Vec<(u64, i128)>,
+}
+
+/// One past the largest `u64`, where the last piece ends.
+const END: i128 = 1 << 64;
+
+impl PiecewiseMap {
+    pub fn identity() -> Self {
+        PiecewiseMap {
+            pieces: vec![(0, 0)],
+        }
+    }
+
+    /// One layer of mappings, where the first mapping listed for a number wins and
+    /// numbers no mapping covers map to themselves.
+    fn from_layer(layer: &[Mapping]) -> Self {
+        let mut bounds: Vec<u64> = layer
+            .iter()
+            .flat_map(|m| [Some(m.src_st), m.src_st.checked_add(m.range_l)])
+            .flatten()
+            .chain([0])
+            .collect();
+        bounds.sort_unstable();
+        bounds.dedup();
+        let pieces = bounds
+            .into_iter()
+            .map(|start| {
+                let offset = layer
+                    .iter()
+                    .find(|m| m.contains(start))
+                    .map_or(0, |m| m.dest_st as i128 - m.src_st as i128);
+                (start, offset)
+            })
+            .collect();
+        PiecewiseMap::merged(pieces)
+    }
+
+    /// Joins neighbouring pieces with the same offset.
+    fn merged(mut pieces: Vec<(u64, i128)>) -> Self {
+        pieces.dedup_by_key(|&mut (_, offset)| offset);
+        PiecewiseMap { pieces }
+    }
+
+    /// The index of the piece `n` falls in; numbers below 0 fall in the first.
+    fn piece(&self, n: i128) -> usize {
+        self.pieces
+            .partition_point(|&(start, _)| start as i128 <= n)
+            .saturating_sub(1)
+    }
+
+    /// Where piece `i` ends, exclusive.
+    fn end(&self, i: usize) -> i128 {
+        self.pieces
+            .get(i + 1)
+            .map_or(END, |&(start, _)| start as i128)
+    }
+
+    /// The number of linear pieces.
+    pub fn len(&self) -> usize {
+        self.pieces.len()
+    }
+
+    /// Whether there are no pieces, which never holds: even the identity has one.
+    pub fn is_empty(&self) -> bool {
+        self.pieces.is_empty()
+    }
+
+    /// This map followed by `next`.
+    pub fn then(&self, next: &PiecewiseMap) -> Self {
+        let mut pieces = Vec::new();
+        for (i, &(start, offset)) in self.pieces.iter().enumerate() {
+            let (mut at, end) = (start as i128, self.end(i));
+            // split the piece where its image crosses into another piece of `next`
+            while at < end {
+                let j = next.piece(at + offset);
+                let split = match next.pieces.get(j + 1) {
+                    Some(&(start, _)) => (start as i128 - offset).min(end),
+                    None => end,
+                };
+                pieces.push((at as u64, offset + next.pieces[j].1));
+                at = split;
+            }
+        }
+        PiecewiseMap::merged(pieces)
+    }
+
+    pub fn map(&self, n: u64) -> u64 {
+        let (_, offset) = self.pieces[self.piece(n as i128)];
+        (n as i128 + offset) as u64
+    }
+
+    /// The image of a `(start, length)` range: one range per piece it spans, in
+    /// the order of the numbers they come from.
+    pub fn map_range(&self, (start, len): Range) -> Vec<Range> {
+        let (mut at, end) = (start as i128, start as i128 + len as i128);
+        let mut i = self.piece(at);
+        let mut image = Vec::new();
+        while at < end {
+            let split = self.end(i).min(end);
+            image.push(((at + self.pieces[i].1) as u64, (split - at) as u64));
+            at = split;
+            i += 1;
+        }
+        image
+    }
+
+    /// The numbers mapped into the `(start, length)` range, as sorted, disjoint
+    /// ranges. Pieces may overlap in what they map to, so this looks at every
+    /// piece rather than searching.
+    pub fn preimage(&self, (start, len): Range) -> Vec<Range> {
+        let (lo, hi) = (start as i128, start as i128 + len as i128);
+        let mut ranges: Vec<Range> = Vec::new();
+        for (i, &(start, offset)) in self.pieces.iter().enumerate() {
+            let from = (start as i128 + offset).max(lo);
+            let to = (self.end(i) + offset).min(hi);
+            if from >= to {
+                continue;
+            }
+            let range = ((from - offset) as u64, (to - from) as u64);
+            match ranges.last_mut() {
+                Some(last) if last.0 + last.1 == range.0 => last.1 += range.1,
+                _ => ranges.push(range),
+            }
+        }
+        ranges
+    }
+
+    /// The numbers mapped below `limit`.
+    pub fn below(&self, limit: u64) -> Vec<Range> {
+        self.preimage((0, limit))
+    }
+}
+
+/// The almanac's layers of mappings, from seed-to-soil to humidity-to-location.
+#[derive(Debug)]
+pub struct Almanac(Vec<Vec<Mapping>>);
+
+impl FromStr for Almanac {
+    type Err = String;
+
+    /// Parses the map blocks of an almanac; a `seeds:` block is skipped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let maps = utils::blocks(&utils::normalize(s))
+            .filter(|block| !block.trim_start().starts_with("seeds:"))
+            .map(|block| block.lines().skip(1).map(str::parse).collect())
+            .collect::<Result<_, _>>()?;
+        Ok(Almanac(maps))
+    }
+}
+
+impl Almanac {
+    /// Maps a seed through every layer to its location.
+    pub fn map(&self, n: u64) -> u64 {
+        let mut mapped = n;
+        for m in &self.0 {
+            for r in m {
+                if let Some(v) = r.get(mapped) {
+                    mapped = v;
+                    break;
+                }
+            }
+        }
+        mapped
+    }
+
+    /// Every layer composed into one map from seed to location.
+    pub fn compose(&self) -> PiecewiseMap {
+        self.0
+            .iter()
+            .map(|layer| PiecewiseMap::from_layer(layer))
+            .fold(PiecewiseMap::identity(), |map, layer| map.then(&layer))
+    }
+
+    /// Maps `(start, length)` ranges of seeds through every layer, returning the
+    /// location ranges they cover in no particular order.
+    pub fn map_ranges(&self, ranges: Vec<Range>) -> Vec<Range> {
+        let mut cur = ranges;
+        for m in &self.0 {
+            let mut next = vec![];
+            for r in m {
+                let mut remain = vec![];
+                while let Some(s) = cur.pop() {
+                    let (intersection, extra) = r.intersect(s);
+                    if let Some(i) = intersection {
+                        next.push(r.conv(i));
+                    }
+                    remain.extend(extra);
+                }
+                cur.extend(remain);
+            }
+            cur.extend(next);
+        }
+        cur
+    }
+}
+
+#[tracing::instrument(name = "parse", level = "debug", skip_all)]
+fn parse_seeds(s: &str) -> Vec<u64> {
+    let num_s = s.split_once(':').unwrap().1.trim();
+    num_s
+        .split_whitespace()
+        .map(|n| n.parse().unwrap())
+        .collect()
+}
+
+#[tracing::instrument(name = "parse", level = "debug", skip_all)]
+fn parse_seeds2(s: &str) -> Vec<(u64, u64)> {
+    let num_s = s.split_once(':').unwrap().1.trim();
+    let mut iter = num_s.split_whitespace();
+
+    let mut seeds = vec![];
+    while let (Some(a), Some(b)) = (iter.next(), iter.next()) {
+        let a: u64 = a.parse().unwrap();
+        let b: u64 = b.parse().unwrap();
+        seeds.push((a, b));
+    }
+    seeds
+}
+
+pub fn solution1(instr: &str) -> Answer {
+    let (seeds_str, maps) = instr.split_once("\n\n").unwrap();
+    let seeds = parse_seeds(seeds_str);
+    let almanac: Almanac = maps.parse().unwrap();
+    let chain = almanac.compose();
+
+    seeds.iter().map(|&s| chain.map(s)).min().unwrap().into()
+}
+
+pub fn solution2(instr: &str) -> Answer {
+    let (seeds_str, maps) = instr.split_once("\n\n").unwrap();
+    let seeds = parse_seeds2(seeds_str);
+    let almanac: Almanac = maps.parse().unwrap();
+    let chain = almanac.compose();
+
+    seeds
+        .into_iter()
+        .flat_map(|range| chain.map_range(range))
+        .map(|c| c.0)
+        .min()
+        .unwrap()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+
+    #[test]
+    fn test_solution1() {
+        let sol = solution1(&std::fs::read_to_string("./data/5t.txt").unwrap());
+        assert_eq!(sol, 35);
+    }
+
+    #[test]
+    fn test_solution2() {
+        let sol = solution2(&std::fs::read_to_string("./data/5t.txt").unwrap());
+        assert_eq!(sol, 46);
+    }
+
+    #[test]
+    fn test_almanac() {
+        let input = std::fs::read_to_string("./data/5t.txt").unwrap();
+        let almanac: Almanac = input.parse().unwrap();
+        assert_eq!(almanac.map(79), 82);
+        assert_eq!(almanac.map(13), 35);
+        let mut ranges = almanac.map_ranges(vec![(82, 1)]);
+        ranges.sort();
+        assert_eq!(ranges, vec![(46, 1)]);
+    }
+
+    #[test]
+    fn test_bad_almanac() {
+        let err = "seed-to-soil map:\n50 98\n".parse::<Almanac>().unwrap_err();
+        assert_eq!(err, "expected `<dest> <src> <len>`, got `50 98`");
+        let err = "seed-to-soil map:\n50 x 2\n"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(err, "bad number `x` in `50 x 2`");
+    }
+
+    #[test]
+    fn test_compose() {
+        let input = std::fs::read_to_string("./data/5t.txt").unwrap();
+        let almanac: Almanac = input.parse().unwrap();
+        let chain = almanac.compose();
+        assert!(chain.len() < 30);
+        for seed in 0..200 {
+            assert_eq!(chain.map(seed), almanac.map(seed), "seed {}", seed);
+        }
+        assert_eq!(chain.map(u64::MAX), u64::MAX);
+
+        for range in [(79, 14), (55, 13), (0, 120), (97, 3)] {
+            let image = chain.map_range(range);
+            assert_eq!(image.iter().map(|r| r.1).sum::<u64>(), range.1);
+            let mut layered = almanac.map_ranges(vec![range]);
+            let mut composed = image.clone();
+            layered.sort();
+            composed.sort();
+            let expand = |ranges: &[Range]| {
+                let mut all: Vec<u64> = ranges.iter().flat_map(|&(s, l)| s..s + l).collect();
+                all.sort();
+                all
+            };
+            assert_eq!(expand(&layered), expand(&composed));
+        }
+        assert!(chain.map_range((5, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_preimage() {
+        let input = std::fs::read_to_string("./data/5t.txt").unwrap();
+        let chain = input.parse::<Almanac>().unwrap().compose();
+
+        let seeds: Vec<u64> = chain
+            .below(47)
+            .iter()
+            .flat_map(|&(s, l)| s..s + l)
+            .collect();
+        let expected: Vec<u64> = (0..300).filter(|&seed| chain.map(seed) < 47).collect();
+        assert_eq!(seeds, expected);
+        assert!(seeds.contains(&82));
+
+        assert_eq!(chain.preimage((35, 1)), [(13, 1)]);
+        assert!(chain.below(0).is_empty());
+        assert_eq!(PiecewiseMap::identity().below(10), [(0, 10)]);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let input = std::fs::read_to_string("./data/5t.txt").unwrap();
+        assert_deterministic(|| (solution1(&input), solution2(&input)));
+    }
+}
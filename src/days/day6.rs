@@ -0,0 +1,88 @@
+use crate::answer::Answer;
+
+fn count_wins(t: u64, d: u64) -> u64 {
+    let mut c: u64 = 0;
+    for i in 1..t {
+        if (t - i) * i > d {
+            c += 1;
+        }
+    }
+    c
+}
+
+pub fn solution1(s: &str) -> Answer {
+    let mut lines = s.lines();
+
+    let times: Vec<u64> = lines
+        .next()
+        .unwrap()
+        .split_whitespace()
+        .filter_map(|s| s.parse::<u64>().ok())
+        .collect();
+
+    let dists: Vec<u64> = lines
+        .next()
+        .unwrap()
+        .split_whitespace()
+        .filter_map(|s| s.parse::<u64>().ok())
+        .collect();
+
+    let mut prod: u64 = 1;
+    for (t, d) in times.iter().zip(dists.iter()) {
+        prod *= count_wins(*t, *d);
+    }
+
+    prod.into()
+}
+
+pub fn solution2(s: &str) -> Answer {
+    let mut lines = s.lines();
+
+    let t: u64 = lines
+        .next()
+        .unwrap()
+        .replace(' ', "")
+        .split_once(':')
+        .unwrap()
+        .1
+        .parse::<u64>()
+        .unwrap();
+
+    let d: u64 = lines
+        .next()
+        .unwrap()
+        .replace(' ', "")
+        .split_once(':')
+        .unwrap()
+        .1
+        .parse::<u64>()
+        .unwrap();
+
+    count_wins(t, d).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+
+    const CASE: &str = r#"Time:      7  15   30
+    Distance:  9  40  200"#;
+
+    #[test]
+    fn test_solution1() {
+        let sol = solution1(CASE);
+        assert_eq!(sol, 288);
+    }
+
+    #[test]
+    fn test_solution2() {
+        let sol = solution2(CASE);
+        assert_eq!(sol, 71503);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        assert_deterministic(|| (solution1(CASE), solution2(CASE)));
+    }
+}
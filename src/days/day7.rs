@@ -0,0 +1,234 @@
+use crate::answer::Answer;
+use crate::checked;
+use crate::explain::{self, Record};
+use std::{cmp::Ordering, fmt::Debug};
+
+const CARDS_P1: &str = "AKQJT98765432";
+const CARDS_P2: &str = "AKQT98765432J";
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy)]
+enum HandType {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+struct Hand {
+    cards: Vec<u8>,
+    bid: u32,
+}
+
+#[tracing::instrument(name = "parse", level = "debug", skip_all)]
+fn parse(input: &str, mappings: &'static str) -> Vec<Hand> {
+    let mut hands = Vec::new();
+
+    for line in input.lines() {
+        let (cards, bid) = line.trim().split_at(5);
+
+        let cards = cards
+            .as_bytes()
+            .iter()
+            .map(|&c| 13 - mappings.find(c as char).unwrap() as u8)
+            .collect();
+        let bid = bid.trim().parse().unwrap();
+
+        hands.push(Hand { cards, bid });
+    }
+
+    hands
+}
+
+fn score_p1(cards: &[u8]) -> HandType {
+    let mut counts = [0; 13];
+    for &c in cards {
+        counts[13 - c as usize] += 1;
+    }
+
+    if counts.contains(&5) {
+        HandType::FiveOfAKind
+    } else if counts.contains(&4) {
+        HandType::FourOfAKind
+    } else if counts.contains(&3) && counts.contains(&2) {
+        HandType::FullHouse
+    } else if counts.contains(&3) {
+        HandType::ThreeOfAKind
+    } else if counts.iter().filter(|&&c| c == 2).count() == 2 {
+        HandType::TwoPair
+    } else if counts.contains(&2) {
+        HandType::OnePair
+    } else {
+        HandType::HighCard
+    }
+}
+
+fn score_p2(cards: &[u8]) -> HandType {
+    let mut counts = [0; 13];
+    for &c in cards {
+        counts[13 - c as usize] += 1;
+    }
+
+    let jacks = counts[12];
+    let mut counts = counts[0..12]
+        .iter()
+        .copied()
+        .filter(|x| *x != 0)
+        .collect::<Vec<_>>();
+    counts.sort_by_key(|x| *x);
+    counts.reverse();
+
+    if counts.len() <= 1 || counts[0] + jacks == 5 {
+        HandType::FiveOfAKind
+    } else if counts[0] + jacks == 4 {
+        HandType::FourOfAKind
+    } else if ((counts[0] + jacks == 3) && (counts[1] == 2))
+        || ((counts[0] == 3) && (counts[1] + jacks == 2))
+    {
+        HandType::FullHouse
+    } else if counts[0] + jacks == 3 {
+        HandType::ThreeOfAKind
+    } else if (counts[0] + jacks == 2 && counts[1] == 2)
+        || (counts[0] == 2 && counts[1] + jacks == 2)
+    {
+        HandType::TwoPair
+    } else if counts[0] + jacks == 2 {
+        HandType::OnePair
+    } else {
+        HandType::HighCard
+    }
+}
+
+fn score_first(a: &[u8], b: &[u8]) -> Ordering {
+    for (&a, &b) in a.iter().zip(b.iter()) {
+        if a != b {
+            return a.cmp(&b);
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// Records every hand's type and rank; `hands` must already be sorted best first.
+fn explain_ranking(hands: &[Hand], mappings: &str, score: fn(&[u8]) -> HandType) {
+    if !explain::enabled() {
+        return;
+    }
+    for (i, hand) in hands.iter().rev().enumerate() {
+        explain::record(|| Record::Hand {
+            cards: hand
+                .cards
+                .iter()
+                .map(|&c| mappings.as_bytes()[13 - c as usize] as char)
+                .collect(),
+            hand_type: format!("{:?}", score(&hand.cards)),
+            rank: i + 1,
+            bid: hand.bid,
+        });
+    }
+}
+
+fn total_winnings(hands: Vec<Hand>) -> u64 {
+    checked::sum(
+        7,
+        "total winnings",
+        hands
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(x, y)| checked::mul(7, "bid * rank", y.bid as u64, x as u64 + 1)),
+    )
+}
+
+pub fn solution1(s: &str) -> Answer {
+    let mut hands = parse(s, CARDS_P1);
+
+    hands.sort_by(|a, b| {
+        let a_score = score_p1(&a.cards) as u8;
+        let b_score = score_p1(&b.cards) as u8;
+
+        if a_score == b_score {
+            score_first(&b.cards, &a.cards)
+        } else {
+            a_score.cmp(&b_score)
+        }
+    });
+    explain_ranking(&hands, CARDS_P1, score_p1);
+    total_winnings(hands).into()
+}
+
+pub fn solution2(s: &str) -> Answer {
+    let mut hands = parse(s, CARDS_P2);
+
+    hands.sort_by(|a, b| {
+        let a_score = score_p2(&a.cards) as u8;
+        let b_score = score_p2(&b.cards) as u8;
+
+        if a_score == b_score {
+            score_first(&b.cards, &a.cards)
+        } else {
+            a_score.cmp(&b_score)
+        }
+    });
+    explain_ranking(&hands, CARDS_P2, score_p2);
+    total_winnings(hands).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+
+    const CASE: &str = "32T3K 765
+    T55J5 684
+    KK677 28
+    KTJJT 220
+    QQQJA 483";
+
+    #[test]
+    fn test_solution1() {
+        let sol = solution1(CASE);
+        assert_eq!(sol, 6440);
+    }
+
+    #[test]
+    fn test_solution2() {
+        let sol = solution2(CASE);
+        assert_eq!(sol, 5905);
+    }
+
+    #[test]
+    fn test_explain_ranking() {
+        let (_, records) = explain::collect(|| solution1(CASE));
+        let ranking: Vec<_> = records
+            .iter()
+            .map(|r| match r {
+                Record::Hand {
+                    cards,
+                    hand_type,
+                    rank,
+                    ..
+                } => (*rank, cards.as_str(), hand_type.as_str()),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            ranking,
+            vec![
+                (1, "32T3K", "OnePair"),
+                (2, "KTJJT", "TwoPair"),
+                (3, "KK677", "TwoPair"),
+                (4, "T55J5", "ThreeOfAKind"),
+                (5, "QQQJA", "ThreeOfAKind"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deterministic() {
+        assert_deterministic(|| (solution1(CASE), solution2(CASE)));
+    }
+}
@@ -0,0 +1,153 @@
+use crate::answer::Answer;
+use crate::cancel;
+use crate::checked;
+use crate::explain::{self, Record};
+use std::collections::HashMap;
+
+#[tracing::instrument(name = "parse", level = "debug", skip_all)]
+fn parse(s: &str) -> (HashMap<&str, (&str, &str)>, &str) {
+    let (instructions, map_str) = s.split_once("\n\n").unwrap();
+
+    let map: HashMap<_, _> = map_str
+        .lines()
+        .map(|l| {
+            let (pos_str, dsts_str) = l.trim().split_once("=").unwrap();
+            let pos_str = pos_str.trim();
+
+            let (l, r) = dsts_str
+                .trim_matches(|c| "() ".contains(c))
+                .split_once(',')
+                .unwrap();
+
+            (pos_str, (l.trim(), r.trim()))
+        })
+        .collect();
+
+    (map, instructions)
+}
+
+pub fn solution1(s: &str) -> Answer {
+    let (map, ins) = parse(s);
+
+    let mut cur = "AAA";
+    for (steps, c) in ins.chars().cycle().enumerate() {
+        cancel::check();
+        let (l, r) = map[cur];
+        match c {
+            'L' => cur = l,
+            'R' => cur = r,
+            _ => unreachable!(),
+        }
+        if cur == "ZZZ" {
+            return (steps as u64 + 1).into();
+        }
+    }
+    unreachable!()
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if a == 0 {
+        b
+    } else {
+        gcd(b % a, a)
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        checked::mul(8, "lcm(a, b)", a / gcd(a, b), b)
+    }
+}
+
+pub fn solution2(s: &str) -> Answer {
+    let (map, ins) = parse(s);
+
+    let mut poses: Vec<_> = map.keys().filter(|p| p.ends_with('A')).copied().collect();
+    poses.sort_unstable();
+    let mut cycles = vec![];
+
+    for p in &mut poses {
+        let start = *p;
+        for (steps, c) in ins.chars().cycle().enumerate() {
+            cancel::check();
+            let (l, r) = map[p];
+            match c {
+                'L' => *p = l,
+                'R' => *p = r,
+                _ => unreachable!(),
+            }
+
+            if p.ends_with('Z') {
+                cycles.push(steps as u64 + 1);
+                explain::record(|| Record::Cycle {
+                    start: start.to_string(),
+                    length: steps as u64 + 1,
+                });
+                break;
+            }
+        }
+    }
+
+    cycles.iter().fold(1, |acc, c| lcm(*c, acc)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::assert_deterministic;
+
+    const CASE1: &str = r#"LLR
+
+    AAA = (BBB, BBB)
+    BBB = (AAA, ZZZ)
+    ZZZ = (ZZZ, ZZZ)"#;
+
+    const CASE2: &str = r#"LR
+
+        11A = (11B, XXX)
+        11B = (XXX, 11Z)
+        11Z = (11B, XXX)
+        22A = (22B, XXX)
+        22B = (22C, 22C)
+        22C = (22Z, 22Z)
+        22Z = (22B, 22B)
+        XXX = (XXX, XXX)"#;
+
+    #[test]
+    fn test_solution1() {
+        let sol = solution1(CASE1);
+        assert_eq!(sol, 6);
+    }
+
+    #[test]
+    fn test_solution2() {
+        let sol = solution2(CASE2);
+        assert_eq!(sol, 6);
+    }
+
+    #[test]
+    fn test_unreachable_zzz_is_cancelled() {
+        let case = "L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        let token = cancel::Token::with_timeout(std::time::Duration::from_millis(10));
+        let err = std::panic::catch_unwind(|| cancel::with_token(token, || solution1(case)));
+        assert!(err.unwrap_err().is::<cancel::Cancelled>());
+    }
+
+    #[test]
+    fn test_explain_cycles() {
+        let (_, mut records) = explain::collect(|| solution2(CASE2));
+        records.sort_by_key(|r| r.to_string());
+        let cycle = |start: &str, length| Record::Cycle {
+            start: start.to_string(),
+            length,
+        };
+        assert_eq!(records, vec![cycle("11A", 2), cycle("22A", 3)]);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        assert_deterministic(|| (solution1(CASE1), solution2(CASE2)));
+    }
+}
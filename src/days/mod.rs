@@ -0,0 +1,209 @@
+use crate::answer::Answer;
+use crate::utils;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub const DAYS: RangeInclusive<u32> = 1..=25;
+
+pub type Solver = fn(&str) -> Answer;
+
+/// Solver versions, one per day. Bump a day's entry when a change could alter its
+/// answers, so the runner's cache stops returning the old ones.
+const VERSIONS: [u32; 25] = [
+    2, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2,
+];
+
+pub fn version(day: u32) -> u32 {
+    VERSIONS[day as usize - 1]
+}
+
+/// Number of parts the day has; day 25 only has one.
+pub fn parts(day: u32) -> u32 {
+    match day {
+        25 => 1,
+        _ => 2,
+    }
+}
+
+/// Default location of a day's puzzle input.
+pub fn input_path(day: u32) -> PathBuf {
+    PathBuf::from(format!("./data/{}.txt", day))
+}
+
+/// Looks up the solver for a day's part, with the puzzle's parameters filled in.
+pub fn solver(day: u32, part: u32) -> Option<Solver> {
+    let solver: Solver = match (day, part) {
+        (1, 1) => day1::solution1,
+        (1, 2) => day1::solution2,
+        (2, 1) => day2::solution1,
+        (2, 2) => day2::solution2,
+        (3, 1) => day3::solution1,
+        (3, 2) => day3::solution2,
+        (4, 1) => day4::solution1,
+        (4, 2) => day4::solution2,
+        (5, 1) => day5::solution1,
+        (5, 2) => day5::solution2,
+        (6, 1) => day6::solution1,
+        (6, 2) => day6::solution2,
+        (7, 1) => day7::solution1,
+        (7, 2) => day7::solution2,
+        (8, 1) => day8::solution1,
+        (8, 2) => day8::solution2,
+        (9, 1) => |s| day9::solutions(s).0,
+        (9, 2) => |s| day9::solutions(s).1,
+        (10, 1) => day10::solution1,
+        (10, 2) => day10::solution2,
+        (11, 1) => |s| day11::solution(s, 2),
+        (11, 2) => |s| day11::solution(s, 1_000_000),
+        (12, 1) => |s| day12::solution(s, false),
+        (12, 2) => |s| day12::solution(s, true),
+        (13, 1) => |s| day13::solution(s, false),
+        (13, 2) => |s| day13::solution(s, true),
+        (14, 1) => day14::solution1,
+        (14, 2) => day14::solution2,
+        (15, 1) => day15::solution1,
+        (15, 2) => day15::solution2,
+        (16, 1) => day16::solution1,
+        (16, 2) => day16::solution2,
+        (17, 1) => |s| day17::solution(s, false),
+        (17, 2) => |s| day17::solution(s, true),
+        (18, 1) => |s| day18::solution(s, false),
+        (18, 2) => |s| day18::solution(s, true),
+        (19, 1) => day19::solution1,
+        (19, 2) => day19::solution2,
+        (20, 1) => day20::solution1,
+        (20, 2) => day20::solution2,
+        (21, 1) => |s| day21::solution(s, 64, false),
+        (21, 2) => |s| day21::solution(s, 26501365, true),
+        (22, 1) => |s| day22::solution(s, false),
+        (22, 2) => |s| day22::solution(s, true),
+        (23, 1) => |s| day23::solution(s, false),
+        (23, 2) => |s| day23::solution(s, true),
+        (24, 1) => day24::solution1,
+        (24, 2) => day24::solution2,
+        (25, 1) => day25::solution,
+        _ => return None,
+    };
+    Some(solver)
+}
+
+/// Solves a day's part, returning `None` for days or parts that do not exist.
+pub fn solve(day: u32, part: u32, input: &str) -> Option<Answer> {
+    let solver = solver(day, part)?;
+    let _span = tracing::info_span!("solve", day, part).entered();
+    let answer = solver(&utils::normalize(input));
+    tracing::info!(%answer, "solved");
+    Some(answer)
+}
+
+/// Runs `solve` on several fresh threads, each of which seeds its `HashMap`s with
+/// new random keys, and checks that every run agrees. Every day's tests call this on
+/// an example so that answers never depend on hash iteration order.
+#[cfg(test)]
+pub(crate) fn assert_deterministic<T>(solve: impl Fn() -> T + Sync)
+where
+    T: PartialEq + std::fmt::Debug + Send,
+{
+    const RUNS: usize = 4;
+    let answers: Vec<T> = std::thread::scope(|s| {
+        let runs: Vec<_> = (0..RUNS).map(|_| s.spawn(&solve)).collect();
+        runs.into_iter().map(|run| run.join().unwrap()).collect()
+    });
+    for answer in &answers[1..] {
+        assert_eq!(answer, &answers[0]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_part_has_a_solver() {
+        for day in DAYS {
+            for part in 1..=parts(day) {
+                assert!(solver(day, part).is_some(), "day {} part {}", day, part);
+            }
+            assert!(solver(day, parts(day) + 1).is_none());
+        }
+        assert!(solver(26, 1).is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = std::fs::read_to_string("./data/1t1.txt").unwrap();
+        assert_eq!(solve(1, 1, &input), Some(Answer::from(142)));
+    }
+
+    #[test]
+    fn test_solve_normalizes_input() {
+        let input = std::fs::read_to_string("./data/5t.txt").unwrap();
+        let crlf = input
+            .lines()
+            .map(|line| format!("{}  \r\n", line))
+            .collect::<String>();
+        assert_eq!(
+            solve(5, 2, &format!("{}\r\n\r\n", crlf)),
+            solve(5, 2, &input)
+        );
+        assert_eq!(solve(5, 2, &input), Some(Answer::from(46)));
+    }
+
+    #[test]
+    fn test_slow_parts_can_be_cancelled() {
+        use crate::cancel::{self, Cancelled, Token};
+        use std::panic::{self, AssertUnwindSafe};
+
+        let slow = [
+            (8, 1),
+            (8, 2),
+            (12, 2),
+            (14, 2),
+            (16, 2),
+            (17, 1),
+            (17, 2),
+            (20, 2),
+            (21, 1),
+            (21, 2),
+            (22, 2),
+            (23, 2),
+            (25, 1),
+        ];
+        for (day, part) in slow {
+            let input = std::fs::read_to_string(input_path(day)).unwrap();
+            let token = Token::new();
+            token.cancel();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                cancel::with_token(token, || solve(day, part, &input))
+            }));
+            let payload = result.expect_err(&format!("day {} part {} ran to the end", day, part));
+            assert!(payload.is::<Cancelled>(), "day {} part {}", day, part);
+        }
+    }
+}
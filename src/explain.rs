@@ -0,0 +1,148 @@
+use serde::Serialize;
+use std::cell::RefCell;
+use std::fmt;
+
+// Explanations of how a day reached its answer. Solvers describe their intermediate
+// results as `Record`s through `record`, which does nothing unless the caller is
+// inside `collect`, so the normal solve path only pays for a thread-local check.
+
+/// An intermediate result worth looking at when an answer is wrong.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Record {
+    /// Day 1: the first and last digit picked from a line.
+    Digits { line: usize, first: u32, last: u32 },
+    /// Day 7: a hand's type and its rank once all hands are sorted.
+    Hand {
+        cards: String,
+        hand_type: String,
+        rank: usize,
+        bid: u32,
+    },
+    /// Day 8: steps a ghost takes from its start node to a `Z` node.
+    Cycle { start: String, length: u64 },
+    /// Day 13: the reflection line of a pattern, after `index` columns or rows.
+    Reflection {
+        pattern: usize,
+        axis: Axis,
+        index: usize,
+    },
+    /// Day 25: an edge cut to split the graph.
+    CutEdge { a: String, b: String },
+    /// Day 25: sizes of the components left after the cuts.
+    Components { sizes: Vec<usize> },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Record::Digits { line, first, last } => {
+                write!(f, "line {}: first {}, last {}", line, first, last)
+            }
+            Record::Hand {
+                cards,
+                hand_type,
+                rank,
+                bid,
+            } => write!(f, "rank {}: {} ({}), bid {}", rank, cards, hand_type, bid),
+            Record::Cycle { start, length } => {
+                write!(f, "ghost from {} reaches Z after {} steps", start, length)
+            }
+            Record::Reflection {
+                pattern,
+                axis,
+                index,
+            } => match axis {
+                Axis::Vertical => write!(f, "pattern {}: mirror after column {}", pattern, index),
+                Axis::Horizontal => write!(f, "pattern {}: mirror after row {}", pattern, index),
+            },
+            Record::CutEdge { a, b } => write!(f, "cut {}/{}", a, b),
+            Record::Components { sizes } => {
+                let sizes: Vec<String> = sizes.iter().map(|s| s.to_string()).collect();
+                write!(f, "component sizes {}", sizes.join(" x "))
+            }
+        }
+    }
+}
+
+thread_local! {
+    static RECORDS: RefCell<Option<Vec<Record>>> = const { RefCell::new(None) };
+}
+
+/// Whether records are being collected; lets a solver skip building a whole batch.
+pub fn enabled() -> bool {
+    RECORDS.with(|records| records.borrow().is_some())
+}
+
+/// Adds a record if one is being collected. The record is only built when needed.
+pub fn record(make: impl FnOnce() -> Record) {
+    RECORDS.with(|records| {
+        if let Some(records) = records.borrow_mut().as_mut() {
+            records.push(make());
+        }
+    });
+}
+
+/// Runs `f`, returning its result with the records it produced on this thread.
+pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Vec<Record>) {
+    // puts back whatever was being collected before, also when `f` panics
+    struct Restore(Option<Vec<Record>>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            RECORDS.with(|records| *records.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(RECORDS.with(|records| records.replace(Some(Vec::new()))));
+    let out = f();
+    let records = RECORDS.with(|records| records.borrow_mut().take());
+    (out, records.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cut(a: &str, b: &str) -> Record {
+        Record::CutEdge {
+            a: a.to_string(),
+            b: b.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_collect() {
+        record(|| unreachable!());
+        assert!(!enabled());
+
+        let ((), records) = collect(|| {
+            record(|| cut("a", "b"));
+            let ((), inner) = collect(|| record(|| cut("c", "d")));
+            assert_eq!(inner, vec![cut("c", "d")]);
+            record(|| cut("e", "f"));
+        });
+        assert_eq!(records, vec![cut("a", "b"), cut("e", "f")]);
+        assert!(!enabled());
+    }
+
+    #[test]
+    fn test_display() {
+        let record = Record::Reflection {
+            pattern: 2,
+            axis: Axis::Horizontal,
+            index: 4,
+        };
+        assert_eq!(record.to_string(), "pattern 2: mirror after row 4");
+        assert_eq!(
+            Record::Components { sizes: vec![9, 6] }.to_string(),
+            "component sizes 9 x 6"
+        );
+    }
+}
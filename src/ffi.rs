@@ -0,0 +1,167 @@
+use crate::checked::Overflow;
+use crate::days;
+use std::any::Any;
+use std::ffi::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::{ptr, slice};
+
+// C ABI for the solvers, exported from the cdylib with the `ffi` feature. build.rs
+// regenerates the matching header in include/aoc2023.h. Solver panics are caught
+// here and turned into status codes so they never unwind into the host.
+
+/// Outcome of `aoc_solve`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    /// The answer was written to the output buffer.
+    Ok = 0,
+    /// The day or part does not exist.
+    NoSuchPart = 1,
+    /// The input is not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The output buffer (or the input with a non-zero length) is NULL.
+    NullArgument = 3,
+    /// The answer did not fit; `len` holds the length it needs, without the NUL.
+    BufferTooSmall = 4,
+    /// The solver panicked, usually on malformed input.
+    Panicked = 5,
+    /// An accumulation overflowed (only detected with the `checked` feature).
+    Overflow = 6,
+}
+
+/// Caller-owned output buffer. On return `data` holds a NUL-terminated answer, or
+/// an error message truncated to fit, and `len` its length without the NUL.
+#[repr(C)]
+pub struct AocBuffer {
+    pub data: *mut c_char,
+    pub capacity: usize,
+    pub len: usize,
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(overflow) = payload.downcast_ref::<Overflow>() {
+        overflow.to_string()
+    } else if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "solver panicked".to_string()
+    }
+}
+
+fn solve(day: u32, part: u32, input: &[u8]) -> (AocStatus, String) {
+    if days::solver(day, part).is_none() {
+        let message = format!("no solver for day {} part {}", day, part);
+        return (AocStatus::NoSuchPart, message);
+    }
+    let Ok(input) = std::str::from_utf8(input) else {
+        return (AocStatus::InvalidUtf8, "input is not UTF-8".to_string());
+    };
+    match panic::catch_unwind(AssertUnwindSafe(|| days::solve(day, part, input).unwrap())) {
+        Ok(answer) => (AocStatus::Ok, answer.to_string()),
+        Err(payload) if payload.is::<Overflow>() => {
+            (AocStatus::Overflow, panic_message(payload.as_ref()))
+        }
+        Err(payload) => (AocStatus::Panicked, panic_message(payload.as_ref())),
+    }
+}
+
+/// Writes as much of `text` as fits, NUL-terminated, and records its full length.
+unsafe fn write(out: &mut AocBuffer, text: &str) -> bool {
+    out.len = text.len();
+    if out.data.is_null() || out.capacity == 0 {
+        return false;
+    }
+    let n = text.len().min(out.capacity - 1);
+    ptr::copy_nonoverlapping(text.as_ptr(), out.data as *mut u8, n);
+    *out.data.add(n) = 0;
+    n == text.len()
+}
+
+/// Solves part `part` of day `day` for the `len` bytes of puzzle text at `input_ptr`
+/// and writes the answer as text to `out_buf`.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `len` readable bytes (it may be NULL when `len` is 0),
+/// `out_buf` must point to a valid `AocBuffer`, and its `data` must point to
+/// `capacity` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input_ptr: *const u8,
+    len: usize,
+    out_buf: *mut AocBuffer,
+) -> AocStatus {
+    if out_buf.is_null() || (input_ptr.is_null() && len > 0) {
+        return AocStatus::NullArgument;
+    }
+    let out = &mut *out_buf;
+    let input = if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(input_ptr, len)
+    };
+
+    let (status, text) = solve(day, part, input);
+    if !write(out, &text) && status == AocStatus::Ok {
+        return AocStatus::BufferTooSmall;
+    }
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn call(day: u32, part: u32, input: &[u8], capacity: usize) -> (AocStatus, String, usize) {
+        let mut data = vec![1 as c_char; capacity];
+        let mut out = AocBuffer {
+            data: data.as_mut_ptr(),
+            capacity,
+            len: 0,
+        };
+        let status = unsafe { aoc_solve(day, part, input.as_ptr(), input.len(), &mut out) };
+        let text = if capacity == 0 {
+            String::new()
+        } else {
+            let text = unsafe { CStr::from_ptr(data.as_ptr()) };
+            text.to_str().unwrap().to_string()
+        };
+        (status, text, out.len)
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = std::fs::read_to_string("./data/1t1.txt").unwrap();
+        assert_eq!(
+            call(1, 1, input.as_bytes(), 16),
+            (AocStatus::Ok, "142".to_string(), 3)
+        );
+        assert_eq!(
+            call(1, 1, input.as_bytes(), 3),
+            (AocStatus::BufferTooSmall, "14".to_string(), 3)
+        );
+        assert_eq!(call(1, 1, input.as_bytes(), 0).0, AocStatus::BufferTooSmall);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            call(25, 2, b"", 64),
+            (
+                AocStatus::NoSuchPart,
+                "no solver for day 25 part 2".to_string(),
+                27
+            )
+        );
+        assert_eq!(call(1, 1, b"\xff", 64).0, AocStatus::InvalidUtf8);
+        assert_eq!(call(1, 1, b"no digits", 64).0, AocStatus::Panicked);
+
+        let null = unsafe { aoc_solve(1, 1, ptr::null(), 4, ptr::null_mut()) };
+        assert_eq!(null, AocStatus::NullArgument);
+    }
+}
@@ -1 +1,18 @@
+pub mod answer;
+pub mod cache;
+pub mod cancel;
+pub mod checked;
+pub mod days;
+pub mod explain;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod lint;
+pub mod memory;
+#[cfg(feature = "python")]
+mod python;
+pub mod repl;
+#[cfg(feature = "server")]
+pub mod server;
 pub mod utils;
+#[cfg(feature = "wasm")]
+pub mod wasm;
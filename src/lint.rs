@@ -0,0 +1,588 @@
+use crate::days::day1::{self, Decoder, Missing};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+// Structural checks for puzzle inputs. Several solvers rely on properties of the real
+// inputs that the puzzle text never promises; these checks make those assumptions explicit.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub check: &'static str,
+    pub passed: bool,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub day: u32,
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    fn new(day: u32) -> Self {
+        Report {
+            day,
+            findings: Vec::new(),
+        }
+    }
+
+    fn check(&mut self, check: &'static str, result: Result<(), String>) {
+        let (passed, detail) = match result {
+            Ok(()) => (true, String::new()),
+            Err(detail) => (false, detail),
+        };
+        self.findings.push(Finding {
+            check,
+            passed,
+            detail,
+        });
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.findings.iter().all(|f| f.passed)
+    }
+
+    pub fn violations(&self) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(|f| !f.passed)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Day {}:", self.day)?;
+        for finding in &self.findings {
+            if finding.passed {
+                writeln!(f, "  ok    {}", finding.check)?;
+            } else {
+                writeln!(f, "  FAIL  {}: {}", finding.check, finding.detail)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs the structural checks for `day` against `input`.
+/// Returns `None` when the day has no checks.
+pub fn lint(day: u32, input: &str) -> Option<Report> {
+    let mut report = Report::new(day);
+    match day {
+        1 => lint_day1(input, &mut report),
+        8 => lint_day8(input, &mut report),
+        10 => lint_day10(input, &mut report),
+        20 => lint_day20(input, &mut report),
+        21 => lint_day21(input, &mut report),
+        _ => return None,
+    }
+    Some(report)
+}
+
+/// `Err` listing the 1-based `lines`, if there are any.
+fn no_lines(lines: &[usize]) -> Result<(), String> {
+    if lines.is_empty() {
+        return Ok(());
+    }
+    let plural = if lines.len() == 1 { "" } else { "s" };
+    let lines: Vec<String> = lines.iter().map(|n| n.to_string()).collect();
+    Err(format!("line{} {}", plural, lines.join(", ")))
+}
+
+fn lint_day1(input: &str, report: &mut Report) {
+    for (check, decoder) in [
+        ("every line has a digit", Decoder::new(&[]).unwrap()),
+        (
+            "every line has a digit or spelled-out digit",
+            Decoder::new(day1::ENGLISH).unwrap(),
+        ),
+    ] {
+        let calibration = decoder.calibrate(input, Missing::Skip).unwrap();
+        report.check(check, no_lines(&calibration.missing));
+    }
+
+    // the solver only reads ASCII digits
+    let unicode: Vec<usize> = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.chars().any(|c| c.is_numeric() && !c.is_ascii_digit()))
+        .map(|(n, _)| n + 1)
+        .collect();
+    report.check("no non-ASCII numerals", no_lines(&unicode));
+}
+
+fn grid(input: &str) -> Vec<&[u8]> {
+    input
+        .lines()
+        .map(|l| l.trim().as_bytes())
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+fn rectangular(grid: &[&[u8]]) -> Result<(), String> {
+    let Some(first) = grid.first() else {
+        return Err("grid is empty".to_string());
+    };
+    match grid.iter().position(|row| row.len() != first.len()) {
+        Some(r) => Err(format!(
+            "row {} has width {}, expected {}",
+            r + 1,
+            grid[r].len(),
+            first.len()
+        )),
+        None => Ok(()),
+    }
+}
+
+fn find_all(grid: &[&[u8]], b: u8) -> Vec<(usize, usize)> {
+    grid.iter()
+        .enumerate()
+        .flat_map(|(r, row)| {
+            row.iter()
+                .enumerate()
+                .filter(move |(_, &c)| c == b)
+                .map(move |(c, _)| (r, c))
+        })
+        .collect()
+}
+
+type Network<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+fn parse_day8(input: &str) -> Result<(&str, Network<'_>), String> {
+    let (instructions, map_str) = input
+        .split_once("\n\n")
+        .ok_or("missing blank line after instructions")?;
+    let instructions = instructions.trim();
+
+    let mut map = HashMap::new();
+    for (i, l) in map_str.lines().map(str::trim).enumerate() {
+        if l.is_empty() {
+            continue;
+        }
+        let parsed = l.split_once('=').and_then(|(pos, dsts)| {
+            let (l, r) = dsts.trim_matches(|c| "() ".contains(c)).split_once(',')?;
+            Some((pos.trim(), (l.trim(), r.trim())))
+        });
+        let Some((pos, dsts)) = parsed else {
+            return Err(format!("node line {} is malformed: {:?}", i + 1, l));
+        };
+        map.insert(pos, dsts);
+    }
+    Ok((instructions, map))
+}
+
+/// Walks from `start` until a node matching `is_end` is reached, returning the
+/// step count and node. Gives up once a (node, instruction) state repeats.
+fn walk_day8<'a>(
+    ins: &[u8],
+    map: &Network<'a>,
+    start: &'a str,
+    offset: usize,
+    is_end: impl Fn(&str) -> bool,
+) -> Option<(usize, &'a str)> {
+    let mut seen = HashSet::new();
+    let mut cur = start;
+    let mut steps = 0;
+    loop {
+        let ip = (offset + steps) % ins.len();
+        if !seen.insert((cur, ip)) {
+            return None;
+        }
+        let (l, r) = map[cur];
+        cur = if ins[ip] == b'L' { l } else { r };
+        steps += 1;
+        if is_end(cur) {
+            return Some((steps, cur));
+        }
+    }
+}
+
+fn lint_day8(input: &str, report: &mut Report) {
+    let (ins, map) = match parse_day8(input) {
+        Ok(parsed) => parsed,
+        Err(e) => return report.check("input parses", Err(e)),
+    };
+    report.check("input parses", Ok(()));
+
+    let ins_ok = if ins.is_empty() {
+        Err("instruction line is empty".to_string())
+    } else if let Some(c) = ins.chars().find(|c| *c != 'L' && *c != 'R') {
+        Err(format!("unexpected instruction {:?}", c))
+    } else {
+        Ok(())
+    };
+    let ins_valid = ins_ok.is_ok();
+    report.check("instructions are L/R only", ins_ok);
+
+    let mut dangling = map
+        .values()
+        .flat_map(|(l, r)| [*l, *r])
+        .filter(|n| !map.contains_key(n))
+        .collect::<Vec<_>>();
+    dangling.sort();
+    dangling.dedup();
+    let closed = dangling.is_empty();
+    report.check(
+        "every destination is defined",
+        if closed {
+            Ok(())
+        } else {
+            Err(format!("undefined nodes: {}", dangling.join(", ")))
+        },
+    );
+    if !ins_valid || !closed {
+        return;
+    }
+    let ins = ins.as_bytes();
+
+    report.check(
+        "AAA reaches ZZZ",
+        if !map.contains_key("AAA") {
+            Err("no AAA node".to_string())
+        } else if walk_day8(ins, &map, "AAA", 0, |n| n == "ZZZ").is_none() {
+            Err("ZZZ is unreachable from AAA".to_string())
+        } else {
+            Ok(())
+        },
+    );
+
+    // solution2 takes the LCM of the first arrival at a Z node, which is only correct
+    // when each ghost then loops back to that same Z after exactly as many steps.
+    let mut starts = map.keys().filter(|n| n.ends_with('A')).collect::<Vec<_>>();
+    starts.sort();
+    let mut problems = vec![];
+    for &start in &starts {
+        let Some((first, z)) = walk_day8(ins, &map, start, 0, |n| n.ends_with('Z')) else {
+            problems.push(format!("{} never reaches a Z node", start));
+            continue;
+        };
+        match walk_day8(ins, &map, z, first, |n| n.ends_with('Z')) {
+            Some((again, z2)) if again == first && z2 == z => {}
+            Some((again, z2)) => problems.push(format!(
+                "{} reaches {} after {} steps, then {} after {} more",
+                start, z, first, z2, again
+            )),
+            None => problems.push(format!("{} leaves {} and never returns", start, z)),
+        }
+    }
+    report.check(
+        "each A node cycles cleanly through one Z node",
+        if starts.is_empty() {
+            Err("no nodes ending in A".to_string())
+        } else if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems.join("; "))
+        },
+    );
+}
+
+fn lint_day10(input: &str, report: &mut Report) {
+    let grid = grid(input);
+    report.check("grid is rectangular", rectangular(&grid));
+
+    let starts = find_all(&grid, b'S');
+    let &[(sr, sc)] = starts.as_slice() else {
+        report.check(
+            "exactly one S",
+            Err(format!("found {} start tiles", starts.len())),
+        );
+        return;
+    };
+    report.check("exactly one S", Ok(()));
+
+    // pipe characters that open towards the start tile from each side
+    let tile = |r: isize, c: isize| -> u8 {
+        if r < 0 || c < 0 {
+            return b'.';
+        }
+        grid.get(r as usize)
+            .and_then(|row| row.get(c as usize))
+            .copied()
+            .unwrap_or(b'.')
+    };
+    let (sr, sc) = (sr as isize, sc as isize);
+    let connections = [
+        (tile(sr - 1, sc), b"|7F"),
+        (tile(sr + 1, sc), b"|LJ"),
+        (tile(sr, sc - 1), b"-LF"),
+        (tile(sr, sc + 1), b"-J7"),
+    ]
+    .iter()
+    .filter(|(t, opens)| opens.contains(t))
+    .count();
+    report.check(
+        "S connects to exactly two pipes",
+        if connections == 2 {
+            Ok(())
+        } else {
+            Err(format!("S connects to {} pipes", connections))
+        },
+    );
+}
+
+fn lint_day20(input: &str, report: &mut Report) {
+    let mut modules = HashMap::new();
+    for (i, line) in input.lines().map(str::trim).enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let Some((label, outputs)) = line.split_once(" -> ") else {
+            return report.check(
+                "input parses",
+                Err(format!("line {} is malformed: {:?}", i + 1, line)),
+            );
+        };
+        let (kind, name) = match label.bytes().next() {
+            Some(kind @ (b'%' | b'&')) => (kind, &label[1..]),
+            _ => (b'b', label),
+        };
+        if name.is_empty() {
+            return report.check(
+                "input parses",
+                Err(format!("line {} has no module name: {:?}", i + 1, line)),
+            );
+        }
+        modules.insert(name, (kind, outputs.split(", ").collect::<Vec<_>>()));
+    }
+    report.check("input parses", Ok(()));
+
+    report.check(
+        "broadcaster exists",
+        if modules.contains_key("broadcaster") {
+            Ok(())
+        } else {
+            Err("no broadcaster module".to_string())
+        },
+    );
+
+    let mut feeders = modules
+        .iter()
+        .filter(|(_, (_, outputs))| outputs.contains(&"rx"))
+        .map(|(name, (kind, _))| (*name, *kind))
+        .collect::<Vec<_>>();
+    feeders.sort();
+    let &[(feeder, kind)] = feeders.as_slice() else {
+        report.check(
+            "a single module feeds rx",
+            Err(format!("{} modules output to rx", feeders.len())),
+        );
+        return;
+    };
+    report.check("a single module feeds rx", Ok(()));
+    report.check(
+        "the rx feeder is a conjunction",
+        if kind == b'&' {
+            Ok(())
+        } else {
+            Err(format!("{} is not a conjunction", feeder))
+        },
+    );
+
+    let mut not_conj = modules
+        .iter()
+        .filter(|(_, (_, outputs))| outputs.contains(&feeder))
+        .filter(|(_, (kind, _))| *kind != b'&')
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+    not_conj.sort();
+    report.check(
+        "the rx feeder's inputs are conjunctions",
+        if not_conj.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("non-conjunction inputs: {}", not_conj.join(", ")))
+        },
+    );
+}
+
+fn lint_day21(input: &str, report: &mut Report) {
+    let grid = grid(input);
+    report.check("grid is rectangular", rectangular(&grid));
+
+    let (h, w) = (grid.len(), grid.first().map_or(0, |r| r.len()));
+    report.check(
+        "grid is square",
+        if h == w {
+            Ok(())
+        } else {
+            Err(format!("grid is {}x{}", w, h))
+        },
+    );
+
+    let starts = find_all(&grid, b'S');
+    let &[(sr, sc)] = starts.as_slice() else {
+        report.check(
+            "exactly one S",
+            Err(format!("found {} start tiles", starts.len())),
+        );
+        return;
+    };
+    report.check("exactly one S", Ok(()));
+
+    report.check(
+        "S is centred",
+        if sr == h / 2 && sc == w / 2 && h % 2 == 1 && w % 2 == 1 {
+            Ok(())
+        } else {
+            Err(format!(
+                "S is at row {}, column {} of a {}x{} grid",
+                sr + 1,
+                sc + 1,
+                w,
+                h
+            ))
+        },
+    );
+
+    let row_rocks = grid[sr].iter().filter(|&&b| b == b'#').count();
+    let col_rocks = grid.iter().filter(|row| row.get(sc) == Some(&b'#')).count();
+    report.check(
+        "row and column through S are open",
+        if row_rocks == 0 && col_rocks == 0 {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} rocks in the S row, {} in the S column",
+                row_rocks, col_rocks
+            ))
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed(report: &Report) -> Vec<&'static str> {
+        report.violations().map(|f| f.check).collect()
+    }
+
+    #[test]
+    fn test_unknown_day() {
+        assert!(lint(2, "").is_none());
+    }
+
+    #[test]
+    fn test_day1() {
+        let input = std::fs::read_to_string("./data/1t2.txt").unwrap();
+        let report = lint(1, &input).unwrap();
+        assert_eq!(failed(&report), vec!["every line has a digit"]);
+        assert_eq!(report.findings[0].detail, "line 2");
+
+        let report = lint(1, "1\nfour\u{665}").unwrap();
+        assert_eq!(
+            failed(&report),
+            vec!["every line has a digit", "no non-ASCII numerals"]
+        );
+    }
+
+    #[test]
+    fn test_day8() {
+        let clean = r#"LR
+
+        11A = (11B, XXX)
+        11B = (XXX, 11Z)
+        11Z = (11B, XXX)
+        22A = (22B, XXX)
+        22B = (22C, 22C)
+        22C = (22Z, 22Z)
+        22Z = (22B, 22B)
+        XXX = (XXX, XXX)"#;
+        let report = lint(8, clean).unwrap();
+        assert_eq!(failed(&report), vec!["AAA reaches ZZZ"]);
+
+        let unreachable = r#"L
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ZZZ = (ZZZ, ZZZ)"#;
+        let report = lint(8, unreachable).unwrap();
+        assert_eq!(
+            failed(&report),
+            vec![
+                "AAA reaches ZZZ",
+                "each A node cycles cleanly through one Z node"
+            ]
+        );
+
+        let offset = r#"L
+
+        AAA = (BBB, BBB)
+        BBB = (ZZZ, ZZZ)
+        ZZZ = (ZZZ, ZZZ)"#;
+        let report = lint(8, offset).unwrap();
+        assert_eq!(
+            failed(&report),
+            vec!["each A node cycles cleanly through one Z node"]
+        );
+    }
+
+    #[test]
+    fn test_day10() {
+        let case = r#".....
+        .S-7.
+        .|.|.
+        .L-J.
+        ....."#;
+        assert!(lint(10, case).unwrap().is_clean());
+
+        let two_starts = r#".....
+        .S-7.
+        .|.|.
+        .L-S.
+        ....."#;
+        assert_eq!(
+            failed(&lint(10, two_starts).unwrap()),
+            vec!["exactly one S"]
+        );
+    }
+
+    #[test]
+    fn test_day20() {
+        let no_rx = r#"broadcaster -> a, b, c
+        %a -> b
+        %b -> c
+        %c -> inv
+        &inv -> a"#;
+        assert_eq!(
+            failed(&lint(20, no_rx).unwrap()),
+            vec!["a single module feeds rx"]
+        );
+
+        let clean = r#"broadcaster -> a, b
+        %a -> x
+        %b -> y
+        &x -> mg
+        &y -> mg
+        &mg -> rx"#;
+        assert!(lint(20, clean).unwrap().is_clean());
+
+        for unnamed in [
+            " -> a",
+            "broadcaster -> a\n% -> a",
+            "broadcaster -> a\n -> a",
+        ] {
+            assert_eq!(failed(&lint(20, unnamed).unwrap()), vec!["input parses"]);
+        }
+    }
+
+    #[test]
+    fn test_day21() {
+        let case = r#"...........
+        .....###.#.
+        .###.##..#.
+        ..#.#...#..
+        ....#.#....
+        .##..S####.
+        .##..#...#.
+        .......##..
+        .##.#.####.
+        .##..##.##.
+        ..........."#;
+        assert_eq!(
+            failed(&lint(21, case).unwrap()),
+            vec!["row and column through S are open"]
+        );
+
+        let off_centre = "S..\n...\n...";
+        assert_eq!(failed(&lint(21, off_centre).unwrap()), vec!["S is centred"]);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::answer::Answer;
+use crate::checked::Overflow;
+use crate::days::{self, day12, day5};
+use pyo3::exceptions::{PyOverflowError, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+use std::panic::{self, AssertUnwindSafe};
+
+// Python bindings, built as the `aoc2023` extension module with the `python` feature.
+// Answers come back as Python ints (or str for text answers); solver failures are
+// raised as exceptions rather than aborting the interpreter.
+
+fn into_py(py: Python<'_>, answer: Answer) -> PyResult<PyObject> {
+    match answer {
+        Answer::Signed(n) => n.into_py_any(py),
+        Answer::Unsigned(n) => n.into_py_any(py),
+        Answer::Big(n) => n.into_py_any(py),
+        Answer::Text(s) => s.into_py_any(py),
+    }
+}
+
+/// Solves a day's part for the given puzzle text.
+#[pyfunction]
+fn solve(py: Python<'_>, day: u32, part: u32, text: &str) -> PyResult<PyObject> {
+    if days::solver(day, part).is_none() {
+        return Err(PyValueError::new_err(format!(
+            "no solver for day {} part {}",
+            day, part
+        )));
+    }
+    let result = py.allow_threads(|| {
+        panic::catch_unwind(AssertUnwindSafe(|| days::solve(day, part, text).unwrap()))
+    });
+    match result {
+        Ok(answer) => into_py(py, answer),
+        Err(payload) => Err(match payload.downcast_ref::<Overflow>() {
+            Some(overflow) => PyOverflowError::new_err(overflow.to_string()),
+            None => PyRuntimeError::new_err(format!("day {} part {} panicked", day, part)),
+        }),
+    }
+}
+
+/// Day 12: the number of ways to fill in the `?` springs of a row so that the
+/// damaged springs form exactly `groups`.
+#[pyfunction]
+fn arrangements(springs: &str, groups: Vec<usize>) -> PyResult<u64> {
+    if groups.is_empty() {
+        return Err(PyValueError::new_err("groups must not be empty"));
+    }
+    Ok(day12::arrangements(springs, &groups))
+}
+
+/// Day 5: the almanac's mapping layers, parsed once and queried many times.
+#[pyclass(frozen)]
+struct Almanac(day5::Almanac, day5::PiecewiseMap);
+
+#[pymethods]
+impl Almanac {
+    #[new]
+    fn new(text: &str) -> PyResult<Self> {
+        let almanac = text
+            .parse::<day5::Almanac>()
+            .map_err(|err| PyValueError::new_err(format!("malformed almanac: {}", err)))?;
+        let chain = almanac.compose();
+        Ok(Almanac(almanac, chain))
+    }
+
+    /// Maps a seed through every layer to its location.
+    fn map(&self, seed: u64) -> u64 {
+        self.0.map(seed)
+    }
+
+    /// Maps `(start, length)` seed ranges to the location ranges they cover.
+    fn map_ranges(&self, ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+        self.0.map_ranges(ranges)
+    }
+
+    /// The `(start, length)` seed ranges whose locations are below `limit`.
+    fn seeds_below(&self, limit: u64) -> Vec<(u64, u64)> {
+        self.1.below(limit)
+    }
+}
+
+#[pymodule]
+fn aoc2023(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(arrangements, m)?)?;
+    m.add_class::<Almanac>()?;
+    Ok(())
+}
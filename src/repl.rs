@@ -0,0 +1,141 @@
+use crate::days::{self, day19, day20, day22};
+use crate::utils;
+use std::panic::{self, AssertUnwindSafe};
+
+// Line-oriented exploration of a day's parsed model, driven by `aoc repl <day>`.
+// Every day gets the generic commands; days with an interesting model add their own
+// through a `Session`, which prints its state with the model's `Display` impls.
+
+/// Day-specific commands over a parsed model.
+pub trait Session {
+    /// `(usage, description)` of each command; the usage starts with its name.
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+
+    /// Runs one of `commands` with the rest of the line as its arguments.
+    fn run(&mut self, command: &str, args: &str) -> Result<String, String>;
+}
+
+/// The session for a day, if it has day-specific commands; fails if the input does
+/// not parse.
+pub fn session(day: u32, input: &str) -> Result<Option<Box<dyn Session>>, String> {
+    Ok(Some(match day {
+        19 => Box::new(day19::Session::new(input)?),
+        20 => Box::new(day20::Session::new(input)?),
+        22 => Box::new(day22::Session::new(input)?),
+        _ => return Ok(None),
+    }))
+}
+
+const GENERIC: &[(&str, &str)] = &[
+    ("solve [part]", "solve one part, or all of them"),
+    ("help", "list the commands"),
+    ("quit", "leave the repl"),
+];
+
+pub enum Outcome {
+    Print(String),
+    Quit,
+}
+
+pub struct Repl {
+    day: u32,
+    input: String,
+    session: Option<Box<dyn Session>>,
+}
+
+impl Repl {
+    pub fn new(day: u32, input: String) -> Result<Self, String> {
+        let input = utils::normalize(&input).into_owned();
+        let session = session(day, &input)?;
+        Ok(Repl {
+            day,
+            input,
+            session,
+        })
+    }
+
+    fn commands(&self) -> impl Iterator<Item = &(&'static str, &'static str)> {
+        let specific = self.session.as_ref().map_or(&[][..], |s| s.commands());
+        specific.iter().chain(GENERIC)
+    }
+
+    fn help(&self) -> String {
+        let width = self.commands().map(|(usage, _)| usage.len()).max().unwrap();
+        self.commands()
+            .map(|(usage, description)| format!("{:<width$}  {}", usage, description))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn solve(&self, args: &str) -> Result<String, String> {
+        let parts: Vec<u32> = match args {
+            "" => (1..=days::parts(self.day)).collect(),
+            part => vec![part.parse().map_err(|_| format!("bad part `{}`", part))?],
+        };
+        parts
+            .into_iter()
+            .map(|part| match days::solve(self.day, part, &self.input) {
+                Some(answer) => Ok(format!("part {}: {}", part, answer)),
+                None => Err(format!("day {} has no part {}", self.day, part)),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|lines| lines.join("\n"))
+    }
+
+    /// Evaluates one line. Panics from bad arguments become errors, so a typo does
+    /// not end the session.
+    pub fn eval(&mut self, line: &str) -> Result<Outcome, String> {
+        let line = line.trim();
+        let (command, args) = line.split_once(' ').unwrap_or((line, ""));
+        let args = args.trim();
+
+        let known = self
+            .commands()
+            .any(|(usage, _)| usage.split(' ').next() == Some(command));
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            match command {
+                "" => Ok(String::new()),
+                "quit" | "exit" => return Ok(Outcome::Quit),
+                "help" => Ok(self.help()),
+                "solve" => self.solve(args),
+                _ if known => self.session.as_mut().unwrap().run(command, args),
+                _ => Err(format!("unknown command `{}`, try `help`", command)),
+            }
+            .map(Outcome::Print)
+        }));
+        result.unwrap_or_else(|_| Err(format!("`{}` failed", line)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(repl: &mut Repl, line: &str) -> Result<String, String> {
+        match repl.eval(line)? {
+            Outcome::Print(out) => Ok(out),
+            Outcome::Quit => Ok("<quit>".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_generic_commands() {
+        let input = std::fs::read_to_string("./data/1t1.txt").unwrap();
+        let mut repl = Repl::new(1, input).unwrap();
+        assert_eq!(eval(&mut repl, "solve 1"), Ok("part 1: 142".to_string()));
+        assert!(eval(&mut repl, "solve 3").is_err());
+        assert!(eval(&mut repl, "help").unwrap().contains("solve [part]"));
+        assert!(eval(&mut repl, "press 3").is_err());
+        assert_eq!(eval(&mut repl, "  "), Ok(String::new()));
+        assert_eq!(eval(&mut repl, "quit"), Ok("<quit>".to_string()));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(Repl::new(19, "px{a<x:A}\n\n{x=1}".to_string()).is_err());
+        assert!(Repl::new(20, "broadcaster -> a\n-> b".to_string()).is_err());
+        assert!(Repl::new(22, "1,0,1~1,2".to_string()).is_err());
+        // days without a session only fail when solved
+        assert!(Repl::new(1, "garbage".to_string()).is_ok());
+    }
+}
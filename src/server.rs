@@ -0,0 +1,243 @@
+use crate::answer::Answer;
+use crate::cancel::{self, Cancelled, Token};
+use crate::checked::Overflow;
+use crate::days;
+use serde::Serialize;
+use std::io::Read;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+// HTTP front end for the solvers. Every request is handled on its own thread and
+// every solve on another one, so a slow day neither blocks other requests nor holds
+// its client past the timeout.
+
+/// Limits applied to each request.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// How long a solve may take before the request fails with 504.
+    pub timeout: Duration,
+    /// Largest accepted puzzle input in bytes; bigger bodies fail with 413.
+    pub max_body: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            timeout: Duration::from_secs(30),
+            max_body: 1 << 20,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Solved {
+    day: u32,
+    part: u32,
+    answer: Answer,
+    time_ms: f64,
+}
+
+#[derive(Serialize)]
+struct Failure {
+    error: String,
+}
+
+enum Route {
+    Health,
+    Solve(u32, u32),
+}
+
+fn route(method: &Method, url: &str) -> Result<Route, (u16, String)> {
+    let segments: Vec<&str> = url.trim_matches('/').split('/').collect();
+    let (route, allowed) = match segments[..] {
+        ["health"] => (Route::Health, Method::Get),
+        ["days", day, "parts", part] => match (day.parse(), part.parse()) {
+            (Ok(day), Ok(part)) => (Route::Solve(day, part), Method::Post),
+            _ => return Err((404, format!("no such endpoint: {}", url))),
+        },
+        _ => return Err((404, format!("no such endpoint: {}", url))),
+    };
+    if *method != allowed {
+        return Err((405, format!("{} expects {}", url, allowed)));
+    }
+    Ok(route)
+}
+
+fn read_input(request: &mut Request, max_body: usize) -> Result<String, (u16, String)> {
+    let too_large = || (413, format!("input larger than {} bytes", max_body));
+    if request.body_length().is_some_and(|len| len > max_body) {
+        return Err(too_large());
+    }
+    // the declared length can be missing (chunked bodies), so limit the read as well
+    let mut body = Vec::new();
+    request
+        .as_reader()
+        .take(max_body as u64 + 1)
+        .read_to_end(&mut body)
+        .map_err(|err| (400, format!("cannot read body: {}", err)))?;
+    if body.len() > max_body {
+        return Err(too_large());
+    }
+    String::from_utf8(body).map_err(|_| (400, "input is not UTF-8".to_string()))
+}
+
+fn timed_out(timeout: Duration) -> (u16, String) {
+    (504, format!("no answer within {:?}", timeout))
+}
+
+fn solve(day: u32, part: u32, input: String, timeout: Duration) -> Result<Solved, (u16, String)> {
+    if days::solver(day, part).is_none() {
+        return Err((404, format!("no solver for day {} part {}", day, part)));
+    }
+
+    let (tx, rx) = mpsc::channel();
+    // the token stops the solver itself once nobody is waiting for it any more; days
+    // that never call `cancel::check` run to the end, but those take milliseconds
+    let token = Token::with_timeout(timeout);
+    thread::spawn(move || {
+        let start = Instant::now();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            cancel::with_token(token, || days::solve(day, part, &input))
+        }));
+        // the receiver is gone if the request already timed out
+        let _ = tx.send((result, start.elapsed()));
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok((Ok(answer), elapsed)) => Ok(Solved {
+            day,
+            part,
+            answer: answer.unwrap(),
+            time_ms: elapsed.as_secs_f64() * 1000.0,
+        }),
+        Ok((Err(payload), _)) if payload.is::<Cancelled>() => Err(timed_out(timeout)),
+        Ok((Err(payload), _)) => Err(match payload.downcast_ref::<Overflow>() {
+            Some(overflow) => (500, overflow.to_string()),
+            None => (500, format!("day {} part {} panicked", day, part)),
+        }),
+        Err(_) => Err(timed_out(timeout)),
+    }
+}
+
+fn handle(request: &mut Request, config: &Config) -> (u16, String) {
+    let result = route(request.method(), request.url()).and_then(|route| match route {
+        Route::Health => Ok(r#"{"status":"ok"}"#.to_string()),
+        Route::Solve(day, part) => {
+            let input = read_input(request, config.max_body)?;
+            let solved = solve(day, part, input, config.timeout)?;
+            Ok(serde_json::to_string(&solved).unwrap())
+        }
+    });
+    match result {
+        Ok(body) => (200, body),
+        Err((status, error)) => (status, serde_json::to_string(&Failure { error }).unwrap()),
+    }
+}
+
+/// Answers requests on `server` until it is shut down:
+///
+/// - `GET /health` returns `{"status":"ok"}`
+/// - `POST /days/{n}/parts/{p}` with the puzzle input as the body returns
+///   `{"day":n,"part":p,"answer":{...},"time_ms":...}`
+///
+/// Errors are returned as `{"error":"..."}` with a matching status code.
+pub fn serve(server: &Server, config: &Config) {
+    thread::scope(|scope| {
+        for mut request in server.incoming_requests() {
+            scope.spawn(move || {
+                let (status, body) = handle(&mut request, config);
+                tracing::info!(
+                    method = %request.method(),
+                    url = request.url(),
+                    status,
+                    "request"
+                );
+                let json = Header::from_bytes("Content-Type", "application/json").unwrap();
+                let response = Response::from_string(body)
+                    .with_status_code(status)
+                    .with_header(json);
+                let _ = request.respond(response);
+            });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::net::TcpStream;
+    use std::sync::Arc;
+
+    fn start(config: Config) -> (Arc<Server>, String) {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let addr = server.server_addr().to_ip().unwrap().to_string();
+        let handle = server.clone();
+        thread::spawn(move || serve(&handle, &config));
+        (server, addr)
+    }
+
+    fn request(addr: &str, method: &str, path: &str, body: &str) -> (u16, serde_json::Value) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let status = response[9..12].parse().unwrap();
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    #[test]
+    fn test_health() {
+        let (server, addr) = start(Config::default());
+        let (status, body) = request(&addr, "GET", "/health", "");
+        assert_eq!(status, 200);
+        assert_eq!(body["status"], "ok");
+        assert_eq!(request(&addr, "POST", "/health", "").0, 405);
+        server.unblock();
+    }
+
+    #[test]
+    fn test_solve() {
+        let (server, addr) = start(Config::default());
+        let input = std::fs::read_to_string("./data/1t1.txt").unwrap();
+        let (status, body) = request(&addr, "POST", "/days/1/parts/1", &input);
+        assert_eq!(status, 200);
+        assert_eq!(body["day"], 1);
+        assert_eq!(body["answer"]["value"], 142);
+        assert!(body["time_ms"].as_f64().unwrap() >= 0.0);
+
+        assert_eq!(request(&addr, "POST", "/days/25/parts/2", "").0, 404);
+        assert_eq!(request(&addr, "POST", "/days/x/parts/1", "").0, 404);
+        assert_eq!(request(&addr, "GET", "/days/1/parts/1", "").0, 405);
+        server.unblock();
+    }
+
+    #[test]
+    fn test_limits() {
+        let (server, addr) = start(Config {
+            timeout: Duration::from_millis(1),
+            max_body: 20_000,
+        });
+        let (status, body) = request(&addr, "POST", "/days/1/parts/1", &"1".repeat(20_001));
+        assert_eq!(status, 413);
+        assert!(body["error"].as_str().unwrap().contains("20000 bytes"));
+
+        let input = std::fs::read_to_string("./data/14.txt").unwrap();
+        let (status, _) = request(&addr, "POST", "/days/14/parts/2", &input);
+        assert_eq!(status, 504);
+        server.unblock();
+    }
+}
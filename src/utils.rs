@@ -1,19 +1,259 @@
+use memmap2::Mmap;
+use std::borrow::Cow;
 use std::fs::File;
-use std::io::{self, BufRead};
+use std::io::{self, BufRead, Read};
+use std::ops::Deref;
 use std::path::Path;
 
-pub fn read_lines<P>(filename: P) -> io::Result<Vec<String>>
+// The one way puzzle input gets into the solvers. Text is normalised so that CRLF
+// line endings, trailing whitespace and trailing blank lines never reach a day:
+// lines are joined with `\n` and the text does not end with a newline.
+
+/// Files at least this large are memory-mapped instead of read into memory.
+pub const MMAP_THRESHOLD: u64 = 64 << 20;
+
+fn is_normal(text: &str) -> bool {
+    !text.contains('\r')
+        && !text.ends_with(char::is_whitespace)
+        && text
+            .lines()
+            .all(|line| !line.ends_with(char::is_whitespace))
+}
+
+/// `text` with every line's trailing whitespace (including a `\r`) and any
+/// trailing blank lines removed; borrowed when there was nothing to remove.
+pub fn normalize(text: &str) -> Cow<'_, str> {
+    if is_normal(text) {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    for line in text.lines() {
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out.truncate(out.trim_end().len());
+    Cow::Owned(out)
+}
+
+/// Blank-line-separated blocks of normalised text, skipping empty ones.
+pub fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    text.split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+enum Contents {
+    Owned(String),
+    Mapped(Mmap),
+}
+
+/// Normalised puzzle input, dereferencing to `&str`. Large files that are already
+/// normalised stay memory-mapped rather than being copied.
+pub struct Input(Contents);
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match &self.0 {
+            Contents::Owned(text) => text,
+            // checked to be UTF-8 when it was mapped
+            Contents::Mapped(map) => unsafe { std::str::from_utf8_unchecked(map) },
+        }
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        match normalize(&text) {
+            Cow::Borrowed(_) => Input(Contents::Owned(text)),
+            Cow::Owned(normal) => Input(Contents::Owned(normal)),
+        }
+    }
+}
+
+fn invalid_utf8(err: std::str::Utf8Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+/// Reads a puzzle input.
+pub fn read_input<P>(filename: P) -> io::Result<Input>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(filename)?;
+    if file.metadata()?.len() < MMAP_THRESHOLD {
+        return io::read_to_string(file).map(Input::from);
+    }
+
+    // the input files are not expected to change while a day runs
+    let map = unsafe { Mmap::map(&file)? };
+    let text = std::str::from_utf8(&map).map_err(invalid_utf8)?;
+    Ok(match normalize(text) {
+        Cow::Borrowed(_) => Input(Contents::Mapped(map)),
+        Cow::Owned(normal) => Input(Contents::Owned(normal)),
+    })
+}
+
+/// Streams normalised lines from a reader without holding more than one at a time.
+/// Unlike `normalize`, trailing blank lines are yielded, since the reader cannot
+/// know they are trailing.
+pub struct Lines<R> {
+    reader: R,
+}
+
+impl<R: BufRead> Lines<R> {
+    pub fn new(reader: R) -> Self {
+        Lines { reader }
+    }
+
+    /// Groups the lines into blank-line-separated blocks.
+    pub fn blocks(self) -> Blocks<R> {
+        Blocks { lines: self }
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => None,
+            Ok(_) => {
+                line.truncate(line.trim_end().len());
+                Some(Ok(line))
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// Streams the lines of blank-line-separated blocks, skipping empty blocks.
+pub struct Blocks<R> {
+    lines: Lines<R>,
+}
+
+impl<R: BufRead> Iterator for Blocks<R> {
+    type Item = io::Result<Vec<String>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut block = Vec::new();
+        for line in self.lines.by_ref() {
+            match line {
+                Ok(line) if line.is_empty() && block.is_empty() => {}
+                Ok(line) if line.is_empty() => return Some(Ok(block)),
+                Ok(line) => block.push(line),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        (!block.is_empty()).then_some(Ok(block))
+    }
+}
+
+/// Streams a reader in chunks of whole lines, so each can be processed on its own
+/// (e.g. in parallel) without holding the whole input. A chunk is at least `size`
+/// bytes unless it is the last, and longer only by the rest of a line. Chunks are
+/// not normalised, but `str::lines` on them already drops `\r\n` endings.
+pub struct Chunks<R> {
+    reader: R,
+    size: usize,
+    carry: Vec<u8>,
+}
+
+impl<R: Read> Chunks<R> {
+    pub fn new(reader: R, size: usize) -> Self {
+        Chunks {
+            reader,
+            size: size.max(1),
+            carry: Vec::new(),
+        }
+    }
+}
+
+impl<R: Read> Iterator for Chunks<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = std::mem::take(&mut self.carry);
+        loop {
+            // fill up to `size`, then keep reading `size` more until a line ends
+            let limit = match self.size.checked_sub(buf.len()) {
+                Some(rest) if rest > 0 => rest,
+                _ => self.size,
+            };
+            match (&mut self.reader).take(limit as u64).read_to_end(&mut buf) {
+                Ok(0) if buf.is_empty() => return None,
+                Ok(0) => break,
+                Ok(_) if buf.len() < self.size => continue,
+                Ok(_) => {
+                    let end = buf.iter().rposition(|&b| b == b'\n');
+                    if let Some(end) = end.filter(|&end| end >= self.size - 1) {
+                        self.carry = buf.split_off(end + 1);
+                        break;
+                    }
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        Some(String::from_utf8(buf).map_err(|err| invalid_utf8(err.utf8_error())))
+    }
+}
+
+/// Streams a file's normalised lines.
+pub fn read_lines<P>(filename: P) -> io::Result<Lines<io::BufReader<File>>>
 where
     P: AsRef<Path>,
 {
     let file = File::open(filename)?;
-    let reader = io::BufReader::new(file);
+    Ok(Lines::new(io::BufReader::new(file)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert!(matches!(normalize("a\nb"), Cow::Borrowed("a\nb")));
+        assert_eq!(normalize("a \r\nb\t\r\n\r\n\n"), "a\nb");
+        assert_eq!(normalize("a\n\n\nb\n"), "a\n\n\nb");
+        assert_eq!(normalize("\n"), "");
+    }
 
-    let mut lines = Vec::new();
+    #[test]
+    fn test_blocks() {
+        let text = normalize("a\r\nb\r\n\r\nc\r\n\r\n\r\nd\r\n");
+        assert_eq!(blocks(&text).collect::<Vec<_>>(), ["a\nb", "c", "d"]);
 
-    for line in reader.lines() {
-        lines.push(line?);
+        let reader = io::Cursor::new("\na\r\nb  \n\nc\n\n\n\nd\n\n");
+        let streamed: Vec<_> = Lines::new(reader).blocks().map(Result::unwrap).collect();
+        assert_eq!(streamed, [vec!["a", "b"], vec!["c"], vec!["d"]]);
     }
 
-    Ok(lines)
+    #[test]
+    fn test_chunks() {
+        let text = "one\ntwo\r\nthree\n\nfour";
+        for size in [1, 3, 4, 8, 100] {
+            let chunks: Vec<_> = Chunks::new(text.as_bytes(), size)
+                .map(Result::unwrap)
+                .collect();
+            assert_eq!(chunks.concat(), text);
+            let (last, whole) = chunks.split_last().unwrap();
+            assert!(whole.iter().all(|c| c.ends_with('\n') && c.len() >= size));
+            assert!(!last.is_empty());
+        }
+        assert!(Chunks::new(&b"a\n\xff\n"[..], 2).any(|c| c.is_err()));
+    }
+
+    #[test]
+    fn test_read_input() {
+        let input = read_input("./data/1t1.txt").unwrap();
+        assert_eq!(input.lines().count(), 4);
+
+        let lines = read_lines("./data/1t1.txt").unwrap();
+        let streamed: Vec<_> = lines.map(Result::unwrap).collect();
+        assert_eq!(streamed.join("\n"), &*input);
+        assert!(read_input("./data/none.txt").is_err());
+    }
 }
@@ -0,0 +1,39 @@
+use crate::days;
+use wasm_bindgen::prelude::*;
+
+// WebAssembly entry points, built with the `wasm` feature for wasm32-unknown-unknown
+// (without the default `z3` feature). Answers are returned as strings since many do
+// not fit in a JavaScript number. Panics cannot be caught on this target, so a solver
+// failing on malformed input surfaces in JavaScript as a `RuntimeError`.
+
+/// Solves a day's part for the given puzzle text.
+#[wasm_bindgen]
+pub fn solve(day: u32, part: u32, input: &str) -> Result<String, JsError> {
+    match days::solve(day, part, input) {
+        Some(answer) => Ok(answer.to_string()),
+        None => Err(JsError::new(&format!(
+            "no solver for day {} part {}",
+            day, part
+        ))),
+    }
+}
+
+macro_rules! day_entries {
+    ($($name:ident => $day:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("Solves a part of day ", stringify!($day), ".")]
+            #[wasm_bindgen]
+            pub fn $name(part: u32, input: &str) -> Result<String, JsError> {
+                solve($day, part, input)
+            }
+        )*
+    };
+}
+
+day_entries!(
+    day1 => 1, day2 => 2, day3 => 3, day4 => 4, day5 => 5,
+    day6 => 6, day7 => 7, day8 => 8, day9 => 9, day10 => 10,
+    day11 => 11, day12 => 12, day13 => 13, day14 => 14, day15 => 15,
+    day16 => 16, day17 => 17, day18 => 18, day19 => 19, day20 => 20,
+    day21 => 21, day22 => 22, day23 => 23, day24 => 24, day25 => 25,
+);
@@ -0,0 +1,39 @@
+// Tests for the WebAssembly build, run with Node instead of a browser:
+//
+//   cargo build --release --lib --target wasm32-unknown-unknown --no-default-features --features wasm
+//   wasm-bindgen --target nodejs --out-dir wasm/pkg target/wasm32-unknown-unknown/release/aoc2023.wasm
+//   node --test wasm/
+
+import assert from "node:assert/strict";
+import { readFileSync } from "node:fs";
+import { createRequire } from "node:module";
+import test from "node:test";
+
+const aoc = createRequire(import.meta.url)("./pkg/aoc2023.js");
+const read = (name) => readFileSync(new URL(`../data/${name}`, import.meta.url), "utf8");
+
+test("day entry points", () => {
+  assert.equal(aoc.day1(1, read("1t1.txt")), "142");
+  assert.equal(aoc.day1(2, read("1t2.txt")), "281");
+  assert.equal(aoc.day5(2, read("5t.txt")), "46");
+});
+
+test("answers beyond 2^53 keep their digits", () => {
+  assert.equal(aoc.day8(2, read("8.txt")), "14631604759649");
+});
+
+test("day 24 part 2 without z3", () => {
+  const hail = [
+    "19, 13, 30 @ -2,  1, -2",
+    "18, 19, 22 @ -1, -1, -2",
+    "20, 25, 34 @ -2, -2, -4",
+    "12, 31, 28 @ -1, -2, -1",
+    "20, 19, 15 @  1, -5, -3",
+  ].join("\n");
+  assert.equal(aoc.day24(2, hail), "47");
+});
+
+test("unknown parts are errors", () => {
+  assert.throws(() => aoc.solve(25, 2, ""), /no solver for day 25 part 2/);
+  assert.throws(() => aoc.day3(3, ""), /no solver/);
+});